@@ -0,0 +1,112 @@
+use crate::json;
+
+use std::fmt;
+
+// The largest magnitude up to which every
+// integer fits exactly in an `f64`.
+const MAX_EXACT_JSON_INTEGER: u64 = 1 << 53;
+
+// What a part returns. Counts that can outgrow an `i64`
+// use `BigInt`, and drawn or spelled out answers `Text`.
+#[derive(Clone, Debug)]
+pub enum Answer {
+    Int(i64),
+    BigInt(u128),
+    Text(String),
+}
+
+impl Answer {
+    // Numbers beyond 2^53 lose precision in most JSON readers,
+    // so those are written as strings, whatever their width.
+    pub fn to_json(&self) -> String {
+        return match self {
+            Answer::Int(value) if value.unsigned_abs() > MAX_EXACT_JSON_INTEGER => json::escape(&value.to_string()),
+            Answer::Int(value) => value.to_string(),
+            Answer::BigInt(value) if *value > u128::from(MAX_EXACT_JSON_INTEGER) => json::escape(&value.to_string()),
+            Answer::BigInt(value) => value.to_string(),
+            Answer::Text(value) => json::escape(value),
+        };
+    }
+
+    pub fn is_multiline(&self) -> bool {
+        return match self {
+            Answer::Text(value) => value.contains('\n'),
+            _ => false,
+        };
+    }
+}
+
+// An `Int` and a `BigInt` of the same value are equal,
+// so an answer may change width without breaking checks.
+impl PartialEq for Answer {
+    fn eq(&self, other: &Answer) -> bool {
+        return match (self, other) {
+            (Answer::Int(left), Answer::Int(right)) => left == right,
+            (Answer::BigInt(left), Answer::BigInt(right)) => left == right,
+            (Answer::Int(left), Answer::BigInt(right)) => u128::try_from(*left) == Ok(*right),
+            (Answer::BigInt(left), Answer::Int(right)) => Ok(*left) == u128::try_from(*right),
+            (Answer::Text(left), Answer::Text(right)) => left == right,
+            _ => false,
+        };
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Answer::Int(value) => write!(f, "{}", value),
+            Answer::BigInt(value) => write!(f, "{}", value),
+            Answer::Text(value) => write!(f, "{}", value),
+        };
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Answer {
+        return Answer::Int(value);
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Answer {
+        return Answer::BigInt(value);
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Answer {
+        return Answer::Text(value);
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Answer {
+        return Answer::Text(value.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integers_compare_by_value() {
+        assert_eq!(Answer::Int(1752), Answer::BigInt(1752));
+        assert_eq!(Answer::BigInt(1752), Answer::Int(1752));
+        assert_ne!(Answer::Int(-1), Answer::BigInt(u128::MAX));
+        assert_ne!(Answer::Int(1752), Answer::from("1752"));
+    }
+
+    #[test]
+    fn to_json_per_variant() {
+        assert_eq!(Answer::Int(-7).to_json(), "-7");
+        assert_eq!(Answer::Int(1 << 53).to_json(), "9007199254740992");
+        assert_eq!(Answer::Int(-(1 << 53) - 1).to_json(), "\"-9007199254740993\"");
+        assert_eq!(Answer::Int(i64::MAX).to_json(), "\"9223372036854775807\"");
+        assert_eq!(Answer::from(3912944_u128).to_json(), "3912944");
+        assert_eq!(Answer::from(1_u128 << 53).to_json(), "9007199254740992");
+        assert_eq!(Answer::from((1_u128 << 53) + 1).to_json(), "\"9007199254740993\"");
+        assert_eq!(Answer::from(u128::MAX).to_json(), "\"340282366920938463463374607431768211455\"");
+        assert_eq!(Answer::from("#  #\n####").to_json(), "\"#  #\\n####\"");
+    }
+}
@@ -0,0 +1,191 @@
+use crate as aoc;
+use crate::Answer;
+use crate::AocError;
+
+use std::collections::HashMap;
+
+// A small subset of TOML: one `[day_XY]` table per day
+// holding `part_N` keys with an integer or string value.
+pub struct Answers {
+    expected: HashMap<(i64, i64), Answer>,
+}
+
+impl Answers {
+    pub fn load(file_name: &str) -> Result<Answers, AocError> {
+        return Answers::parse(&aoc::load(file_name)?);
+    }
+
+    pub fn parse(input: &str) -> Result<Answers, AocError> {
+        let mut answers = Answers {
+            expected: HashMap::new(),
+        };
+
+        let mut day = None;
+        for (number, line) in aoc::numbered_lines(input) {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+
+            if let Some(table) = trimmed.strip_prefix('[') {
+                day = Some(parse_table(number, line, table)?);
+                continue;
+            }
+
+            let (key, value) = match trimmed.split_once('=') {
+                Some(value) => value,
+                None => return Err(aoc::missing_token(number, line, "Expected `part_N = VALUE`")),
+            };
+
+            let part = parse_key(number, line, key.trim())?;
+            let day = match day {
+                Some(value) => value,
+                None => {
+                    let column = aoc::column_of(line, key);
+                    return Err(AocError::parse(number, column, "Answer found outside of a `[day_XY]` table"));
+                },
+            };
+
+            let value = parse_value(number, line, value.trim())?;
+            if answers.expected.insert((day, part), value).is_some() {
+                let message = format!("Duplicate answer for day {} part {}", day, part);
+                return Err(AocError::parse(number, aoc::column_of(line, key), &message));
+            }
+        }
+
+        return Ok(answers);
+    }
+
+    pub fn get(&self, day: i64, part: i64) -> Option<&Answer> {
+        return self.expected.get(&(day, part));
+    }
+}
+
+fn parse_table(number: usize, line: &str, table: &str) -> Result<i64, AocError> {
+    let name = match table.strip_suffix(']') {
+        Some(value) => value.trim(),
+        None => return Err(aoc::missing_token(number, line, "No closing `]` found")),
+    };
+
+    return match name.strip_prefix("day_") {
+        Some(day) => aoc::parse_token(number, line, day),
+        None => {
+            let message = format!("Unknown table: {}", name);
+            Err(AocError::parse(number, aoc::column_of(line, name), &message))
+        },
+    };
+}
+
+fn parse_key(number: usize, line: &str, key: &str) -> Result<i64, AocError> {
+    let part = match key.strip_prefix("part_") {
+        Some(value) => aoc::parse_token(number, line, value)?,
+        None => {
+            let message = format!("Unknown key: {}", key);
+            return Err(AocError::parse(number, aoc::column_of(line, key), &message));
+        },
+    };
+
+    if !(1..=2).contains(&part) {
+        let message = format!("Unknown part: {}", part);
+        return Err(AocError::parse(number, aoc::column_of(line, key), &message));
+    }
+
+    return Ok(part);
+}
+
+fn parse_value(number: usize, line: &str, value: &str) -> Result<Answer, AocError> {
+    if let Some(quoted) = value.strip_prefix('"') {
+        return parse_string(number, line, quoted).map(Answer::Text);
+    }
+
+    // Bare values are integers, optionally followed by a comment.
+    let bare = match value.split_once('#') {
+        Some((before, _)) => before.trim(),
+        None => value,
+    };
+
+    // Anything beyond an `i64` is still a valid answer.
+    if let Ok(integer) = bare.parse::<i64>() {
+        return Ok(Answer::Int(integer));
+    }
+
+    let integer: u128 = aoc::parse_token(number, line, bare)?;
+    return Ok(Answer::BigInt(integer));
+}
+
+fn parse_string(number: usize, line: &str, quoted: &str) -> Result<String, AocError> {
+    let mut value = String::new();
+
+    let mut chars = quoted.char_indices();
+    while let Some((index, ch)) = chars.next() {
+        match ch {
+            '"' => {
+                let rest = quoted[(index + 1)..].trim();
+                if !rest.is_empty() && !rest.starts_with('#') {
+                    let message = format!("Unexpected text after string: {}", rest);
+                    return Err(AocError::parse(number, aoc::column_of(line, rest), &message));
+                }
+
+                return Ok(value);
+            },
+            '\\' => {
+                match chars.next() {
+                    Some((_, 'n')) => value.push('\n'),
+                    Some((_, 't')) => value.push('\t'),
+                    Some((_, '"')) => value.push('"'),
+                    Some((_, '\\')) => value.push('\\'),
+                    _ => {
+                        let column = aoc::column_of(line, &quoted[index..]);
+                        return Err(AocError::parse(number, column, "Unknown escape sequence"));
+                    },
+                };
+            },
+            _ => value.push(ch),
+        };
+    }
+
+    return Err(aoc::missing_token(number, line, "No closing `\"` found"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_tables_and_values() {
+        let input = "\
+# Answers for the tests
+[day_01]
+part_1 = 1752  # a trailing comment
+part_2 = \"a \\\"b\\\"\\n\\\\\"
+
+[ day_13 ]
+  part_1 = 340282366920938463463374607431768211455
+";
+
+        let answers = Answers::parse(input).unwrap();
+        assert_eq!(answers.get(1, 1), Some(&Answer::Int(1752)));
+        assert_eq!(answers.get(1, 2), Some(&Answer::from("a \"b\"\n\\")));
+        assert_eq!(answers.get(13, 1), Some(&Answer::BigInt(u128::MAX)));
+        assert_eq!(answers.get(13, 2), None);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let examples = [
+            ("part_1 = 1", "line 1, column 1: Answer found outside of a `[day_XY]` table"),
+            ("[day_01\npart_1 = 1", "line 1, column 8: No closing `]` found"),
+            ("[days_01]", "line 1, column 2: Unknown table: days_01"),
+            ("[day_01]\npart_1", "line 2, column 7: Expected `part_N = VALUE`"),
+            ("[day_01]\npart_3 = 1", "line 2, column 1: Unknown part: 3"),
+            ("[day_01]\npart_1 = 1\npart_1 = 2", "line 3, column 1: Duplicate answer for day 1 part 1"),
+            ("[day_01]\npart_1 = \"a\\q\"", "line 2, column 12: Unknown escape sequence"),
+            ("[day_01]\npart_1 = \"abc", "line 2, column 14: No closing `\"` found"),
+            ("[day_01]\npart_1 = \"a\" b", "line 2, column 14: Unexpected text after string: b"),
+        ];
+
+        for (input, expected) in examples {
+            assert_eq!(Answers::parse(input).err().unwrap().to_string(), expected, "{}", input);
+        }
+    }
+}
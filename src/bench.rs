@@ -0,0 +1,267 @@
+use crate as aoc;
+use crate::instrument;
+use crate::instrument::Report;
+use crate::json;
+use crate::json::Json;
+use crate::AocError;
+use crate::Puzzle;
+
+use std::collections::HashMap;
+use std::process::Command;
+use std::time::Instant;
+
+pub const WARMUP: usize = 1;
+pub const ITERATIONS: usize = 5;
+
+pub struct TimedPhase {
+    pub name: String,
+    pub durations: Vec<f64>,
+}
+
+impl TimedPhase {
+    fn new(name: &str) -> TimedPhase {
+        return TimedPhase {
+            name: name.to_string(),
+            durations: vec![],
+        };
+    }
+
+    pub fn min(&self) -> f64 {
+        return self.sorted()[0];
+    }
+
+    pub fn median(&self) -> f64 {
+        let sorted = self.sorted();
+        return sorted[sorted.len() / 2];
+    }
+
+    pub fn max(&self) -> f64 {
+        let sorted = self.sorted();
+        return sorted[sorted.len() - 1];
+    }
+
+    fn sorted(&self) -> Vec<f64> {
+        let mut sorted = self.durations.clone();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        return sorted;
+    }
+}
+
+pub struct TimedResult {
+    pub day: i64,
+    pub phases: Vec<TimedPhase>,
+    pub report: Report,
+    pub peak_memory: usize,
+}
+
+impl TimedResult {
+    fn new(day: i64, parts: &[i64]) -> TimedResult {
+        let mut phases = vec![TimedPhase::new("Parse")];
+        for part in parts {
+            phases.push(TimedPhase::new(&format!("Part {}", part)));
+        }
+
+        return TimedResult {
+            day: day,
+            phases: phases,
+            report: Report::default(),
+            peak_memory: 0,
+        };
+    }
+}
+
+pub fn benchmark(puzzle: &Puzzle, input: &str, parts: &[i64]) -> Result<TimedResult, AocError> {
+    let mut timed_result = TimedResult::new(puzzle.day, parts);
+    for iteration in 0..(WARMUP + ITERATIONS) {
+        instrument::take();
+        instrument::reset_peak();
+        let allocated = instrument::allocated();
+
+        let now = Instant::now();
+        let solution = (puzzle.parse)(&mut input.as_bytes())?;
+        let mut durations = vec![now.elapsed()];
+
+        for part in parts {
+            let now = Instant::now();
+            solution.solve(*part)?;
+
+            durations.push(now.elapsed());
+        }
+
+        if iteration >= WARMUP {
+            for (phase, duration) in timed_result.phases.iter_mut().zip(durations) {
+                phase.durations.push(duration.as_secs_f64());
+            }
+        }
+
+        // Every iteration does the same work, so
+        // only the report of the last one is kept.
+        timed_result.report = instrument::take();
+        timed_result.peak_memory = timed_result.peak_memory.max(instrument::peak().saturating_sub(allocated));
+    }
+
+    return Ok(timed_result);
+}
+
+// Falls back to "unknown" so benchmarks still
+// work outside of a git checkout.
+pub fn git_revision() -> String {
+    let output = Command::new("git")
+        .args(["describe", "--always", "--dirty"])
+        .output();
+
+    return match output {
+        Ok(value) if value.status.success() => String::from_utf8_lossy(&value.stdout).trim().to_string(),
+        _ => String::from("unknown"),
+    };
+}
+
+pub fn to_json(revision: &str, timed_results: &[TimedResult]) -> String {
+    let mut results = vec![];
+    for timed_result in timed_results {
+        let mut phases = vec![];
+        for phase in &timed_result.phases {
+            let durations: Vec<String> = phase.durations.iter().map(|value| value.to_string()).collect();
+            phases.push(format!(
+                "        {{ \"name\": {}, \"min\": {}, \"median\": {}, \"max\": {}, \"durations\": [{}] }}",
+                json::escape(&phase.name), phase.min(), phase.median(), phase.max(), durations.join(", ")));
+        }
+
+        results.push(format!("    {{\n      \"day\": {},\n      \"phases\": [\n{}\n      ]\n    }}",
+            timed_result.day, phases.join(",\n")));
+    }
+
+    return format!("{{\n  \"revision\": {},\n  \"warmup\": {},\n  \"iterations\": {},\n  \"results\": [\n{}\n  ]\n}}\n",
+        json::escape(revision), WARMUP, ITERATIONS, results.join(",\n"));
+}
+
+pub fn to_csv(revision: &str, timed_results: &[TimedResult]) -> String {
+    let mut csv = String::from("revision,day,phase,min,median,max\n");
+    for timed_result in timed_results {
+        for phase in &timed_result.phases {
+            csv.push_str(&format!("{},{},{},{},{},{}\n", revision, timed_result.day,
+                phase.name, phase.min(), phase.median(), phase.max()));
+        }
+    }
+
+    return csv;
+}
+
+pub struct Baseline {
+    pub revision: String,
+    medians: HashMap<(i64, String), f64>,
+}
+
+impl Baseline {
+    pub fn load(file_name: &str) -> Result<Baseline, AocError> {
+        return Baseline::parse(&aoc::load(file_name)?);
+    }
+
+    pub fn parse(input: &str) -> Result<Baseline, AocError> {
+        let root = json::parse(input)?;
+
+        let mut baseline = Baseline {
+            revision: match root.get("revision").and_then(Json::as_str) {
+                Some(value) => value.to_string(),
+                None => String::from("unknown"),
+            },
+            medians: HashMap::new(),
+        };
+
+        let results = match root.get("results").and_then(Json::as_array) {
+            Some(value) => value,
+            None => return Err(AocError::parse(1, 1, "No `results` array found")),
+        };
+
+        for result in results {
+            let day = match result.get("day").and_then(Json::as_f64) {
+                Some(value) => value as i64,
+                None => return Err(AocError::parse(1, 1, "Result without a `day` found")),
+            };
+
+            for phase in result.get("phases").and_then(Json::as_array).unwrap_or(&[]) {
+                let name = phase.get("name").and_then(Json::as_str);
+                let median = phase.get("median").and_then(Json::as_f64);
+
+                match (name, median) {
+                    (Some(name), Some(median)) => baseline.medians.insert((day, name.to_string()), median),
+                    _ => return Err(AocError::parse(1, 1, "Phase without a `name` and `median` found")),
+                };
+            }
+        }
+
+        return Ok(baseline);
+    }
+
+    pub fn median(&self, day: i64, phase: &str) -> Option<f64> {
+        return self.medians.get(&(day, phase.to_string())).copied();
+    }
+}
+
+pub struct Regression {
+    pub day: i64,
+    pub phase: String,
+    pub baseline: f64,
+    pub current: f64,
+}
+
+pub fn percent_change(baseline: f64, current: f64) -> f64 {
+    return (current - baseline) / baseline * 100.0;
+}
+
+// Compares medians, since a single slow sample (the OS
+// scheduling something else) should not fail a run.
+pub fn find_regressions(timed_results: &[TimedResult], baseline: &Baseline, threshold: f64) -> Vec<Regression> {
+    let mut regressions = vec![];
+    for timed_result in timed_results {
+        for phase in &timed_result.phases {
+            if let Some(median) = baseline.median(timed_result.day, &phase.name) {
+                if percent_change(median, phase.median()) > threshold {
+                    regressions.push(Regression {
+                        day: timed_result.day,
+                        phase: phase.name.clone(),
+                        baseline: median,
+                        current: phase.median(),
+                    });
+                }
+            }
+        }
+    }
+
+    return regressions;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timed_result(day: i64, medians: &[f64]) -> TimedResult {
+        let mut timed_result = TimedResult::new(day, &[1, 2]);
+        for (phase, median) in timed_result.phases.iter_mut().zip(medians) {
+            phase.durations = vec![median * 2.0, *median, median / 2.0];
+        }
+
+        return timed_result;
+    }
+
+    #[test]
+    fn baseline_reads_back_json() {
+        let baseline = Baseline::parse(&to_json("abc1234", &[timed_result(7, &[0.5, 0.25, 0.125])])).unwrap();
+
+        assert_eq!(baseline.revision, "abc1234");
+        assert_eq!(baseline.median(7, "Parse"), Some(0.5));
+        assert_eq!(baseline.median(7, "Part 2"), Some(0.125));
+        assert_eq!(baseline.median(8, "Parse"), None);
+    }
+
+    #[test]
+    fn regressions_above_threshold() {
+        let baseline = Baseline::parse(&to_json("abc1234", &[timed_result(7, &[1.0, 1.0, 1.0])])).unwrap();
+        let current = [timed_result(7, &[1.05, 1.2, 0.5])];
+
+        let regressions = find_regressions(&current, &baseline, 10.0);
+        assert_eq!(regressions.len(), 1);
+        assert_eq!(regressions[0].phase, "Part 1");
+        assert_eq!(regressions[0].current, 1.2);
+    }
+}
@@ -0,0 +1,313 @@
+use crate as aoc;
+use crate::AocError;
+use crate::Solution;
+
+use std::collections::VecDeque;
+use std::io::BufRead;
+
+// Both parts are answered as the depths are read, so only the
+// two windows are kept. Other windows go through `analyze_lines`.
+pub struct Day01 {
+    increases: i64,
+    window_increases: i64,
+}
+
+impl Solution for Day01 {
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Day01, AocError> {
+        return Day01::parse_stream(&mut input.as_bytes());
+    }
+
+    fn parse_stream(reader: &mut dyn BufRead) -> Result<Day01, AocError> {
+        let mut single = SonarSweep::new(1, Aggregate::Sum);
+        let mut window = SonarSweep::new(3, Aggregate::Sum);
+
+        let mut lines = aoc::stream_lines(reader);
+        while let Some(line) = lines.next_line() {
+            let (number, line) = line?;
+            let depth = aoc::parse_token(number, line, line)?;
+
+            single.push(depth);
+            window.push(depth);
+        }
+
+        return Ok(Day01 {
+            increases: single.trends.increases,
+            window_increases: window.trends.increases,
+        });
+    }
+
+    fn part_1(&self) -> Result<i64, AocError> {
+        return solve_part_1(self);
+    }
+
+    fn part_2(&self) -> Result<i64, AocError> {
+        return solve_part_2(self);
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Aggregate {
+    Sum,
+    Mean,
+    Min,
+    Max,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Trend {
+    Increase,
+    Decrease,
+    Plateau,
+}
+
+// How consecutive windows compare. A streak is the number
+// of comparisons in a row that went the same way.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Trends {
+    pub increases: i64,
+    pub decreases: i64,
+    pub plateaus: i64,
+    pub longest_increase: i64,
+    pub longest_decrease: i64,
+    pub longest_plateau: i64,
+}
+
+// Only ever holds the current window, so depths can be
+// pushed one at a time from a log of any length.
+pub struct SonarSweep {
+    size: usize,
+    aggregate: Aggregate,
+    window: VecDeque<i64>,
+    sum: i128,
+    // Candidates for the minimum and maximum, ordered so
+    // that the current one is always at the front.
+    minimums: VecDeque<i64>,
+    maximums: VecDeque<i64>,
+    previous: Option<i128>,
+    streak: Option<(Trend, i64)>,
+    trends: Trends,
+}
+
+impl SonarSweep {
+    // A window size of 0 is treated as 1.
+    pub fn new(size: usize, aggregate: Aggregate) -> SonarSweep {
+        return SonarSweep {
+            size: size.max(1),
+            aggregate: aggregate,
+            window: VecDeque::with_capacity(size.max(1)),
+            sum: 0,
+            minimums: VecDeque::with_capacity(size.max(1)),
+            maximums: VecDeque::with_capacity(size.max(1)),
+            previous: None,
+            streak: None,
+            trends: Trends::default(),
+        };
+    }
+
+    // Returns the aggregate of the window once it is full.
+    pub fn push(&mut self, depth: i64) -> Option<f64> {
+        if self.window.len() == self.size {
+            if let Some(oldest) = self.window.pop_front() {
+                self.sum -= i128::from(oldest);
+
+                if self.minimums.front() == Some(&oldest) {
+                    self.minimums.pop_front();
+                }
+
+                if self.maximums.front() == Some(&oldest) {
+                    self.maximums.pop_front();
+                }
+            }
+        }
+
+        self.window.push_back(depth);
+        self.sum += i128::from(depth);
+
+        while self.minimums.back().is_some_and(|&minimum| minimum > depth) {
+            self.minimums.pop_back();
+        }
+
+        while self.maximums.back().is_some_and(|&maximum| maximum < depth) {
+            self.maximums.pop_back();
+        }
+
+        self.minimums.push_back(depth);
+        self.maximums.push_back(depth);
+        if self.window.len() < self.size {
+            return None;
+        }
+
+        let value = self.exact_value();
+        if let Some(previous) = self.previous {
+            if value > previous {
+                self.record(Trend::Increase);
+            } else if value < previous {
+                self.record(Trend::Decrease);
+            } else {
+                self.record(Trend::Plateau);
+            }
+        }
+
+        self.previous = Some(value);
+        return Some(match self.aggregate {
+            Aggregate::Mean => value as f64 / self.size as f64,
+            _ => value as f64,
+        });
+    }
+
+    pub fn trends(&self) -> &Trends {
+        return &self.trends;
+    }
+
+    // Every full window has the same size, so means are
+    // compared by their sums, which keeps comparisons exact.
+    fn exact_value(&self) -> i128 {
+        return match self.aggregate {
+            Aggregate::Sum | Aggregate::Mean => self.sum,
+            Aggregate::Min => self.minimums.front().copied().map_or(0, i128::from),
+            Aggregate::Max => self.maximums.front().copied().map_or(0, i128::from),
+        };
+    }
+
+    fn record(&mut self, trend: Trend) {
+        let length = match self.streak {
+            Some((current, length)) if current == trend => length + 1,
+            _ => 1,
+        };
+
+        self.streak = Some((trend, length));
+        let (count, longest) = match trend {
+            Trend::Increase => (&mut self.trends.increases, &mut self.trends.longest_increase),
+            Trend::Decrease => (&mut self.trends.decreases, &mut self.trends.longest_decrease),
+            Trend::Plateau => (&mut self.trends.plateaus, &mut self.trends.longest_plateau),
+        };
+
+        *count += 1;
+        *longest = (*longest).max(length);
+    }
+}
+
+pub fn analyze<I: IntoIterator<Item = i64>>(depths: I, size: usize, aggregate: Aggregate) -> Trends {
+    let mut sweep = SonarSweep::new(size, aggregate);
+    for depth in depths {
+        sweep.push(depth);
+    }
+
+    return sweep.trends;
+}
+
+// Analyzes a sensor log straight from a reader, one
+// depth per line, without keeping the depths around.
+pub fn analyze_lines<R: BufRead>(reader: R, size: usize, aggregate: Aggregate) -> Result<Trends, AocError> {
+    let mut sweep = SonarSweep::new(size, aggregate);
+
+    let mut lines = aoc::stream_lines(reader);
+    while let Some(line) = lines.next_line() {
+        let (number, line) = line?;
+        sweep.push(aoc::parse_token(number, line, line)?);
+    }
+
+    return Ok(sweep.trends);
+}
+
+fn solve_part_1(day: &Day01) -> Result<i64, AocError> {
+    return Ok(day.increases);
+}
+
+fn solve_part_2(day: &Day01) -> Result<i64, AocError> {
+    return Ok(day.window_increases);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+199
+200
+208
+210
+200
+207
+240
+269
+260
+263";
+
+    #[test]
+    fn part_1_example() {
+        let day = Day01::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part_1().unwrap(), 7);
+    }
+
+    #[test]
+    fn part_2_example() {
+        let day = Day01::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part_2().unwrap(), 5);
+    }
+
+    #[test]
+    fn trends_of_single_depths() {
+        assert_eq!(trends(1, Aggregate::Sum), Trends {
+            increases: 7,
+            decreases: 2,
+            plateaus: 0,
+            longest_increase: 3,
+            longest_decrease: 1,
+            longest_plateau: 0,
+        });
+    }
+
+    #[test]
+    fn trends_per_aggregate() {
+        assert_eq!(trends(3, Aggregate::Mean), trends(3, Aggregate::Sum));
+        assert_eq!(trends(3, Aggregate::Sum).plateaus, 1);
+        assert_eq!(trends(3, Aggregate::Sum).longest_increase, 4);
+        assert_eq!(trends(3, Aggregate::Min).longest_plateau, 3);
+        assert_eq!(trends(3, Aggregate::Max).plateaus, 4);
+        assert_eq!(trends(3, Aggregate::Max).longest_plateau, 2);
+    }
+
+    #[test]
+    fn sweep_keeps_only_the_window() {
+        let mut sweep = SonarSweep::new(3, Aggregate::Sum);
+        let values: Vec<Option<f64>> = [199, 200, 208, 210].into_iter().map(|depth| sweep.push(depth)).collect();
+
+        assert_eq!(values, [None, None, Some(607.0), Some(618.0)]);
+        assert_eq!(sweep.window.len(), 3);
+        assert_eq!(sweep.trends().increases, 1);
+    }
+
+    #[test]
+    fn sweep_compares_exactly() {
+        // As `f64` every sum rounds to 2^64, hiding the last increase.
+        let depths = [i64::MAX - 1, i64::MAX - 1, 1, i64::MAX - 1, i64::MAX - 1, 2];
+        assert_eq!(analyze(depths, 3, Aggregate::Sum).increases, 1);
+        assert_eq!(analyze(depths, 3, Aggregate::Mean).increases, 1);
+
+        let depths = [5, 1, 4, 1, 3, 9, 2, 6];
+        assert_eq!(analyze(depths, 3, Aggregate::Min), analyze_naively(&depths, 3, |window| window.iter().min()));
+        assert_eq!(analyze(depths, 3, Aggregate::Max), analyze_naively(&depths, 3, |window| window.iter().max()));
+    }
+
+    fn analyze_naively(depths: &[i64], size: usize, aggregate: fn(&[i64]) -> Option<&i64>) -> Trends {
+        let values = depths.windows(size).filter_map(aggregate).copied();
+        return analyze(values, 1, Aggregate::Sum);
+    }
+
+    #[test]
+    fn analyze_lines_matches_analyze() {
+        let depths = EXAMPLE.lines().map(|line| line.parse().unwrap());
+        assert_eq!(trends(3, Aggregate::Max), analyze(depths, 3, Aggregate::Max));
+
+        let error = analyze_lines("199\n2x0\n".as_bytes(), 1, Aggregate::Sum).unwrap_err();
+        assert!(error.to_string().starts_with("line 2, column 1: "));
+    }
+
+    fn trends(size: usize, aggregate: Aggregate) -> Trends {
+        return analyze_lines(EXAMPLE.as_bytes(), size, aggregate).unwrap();
+    }
+}
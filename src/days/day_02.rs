@@ -0,0 +1,324 @@
+use crate as aoc;
+use crate::AocError;
+use crate::Solution;
+
+use std::io::BufRead;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Action {
+    Up,
+    Down,
+    Forward,
+    Back,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Command {
+    Move(Action, i64),
+    Reset,
+    Repeat(i64, Vec<Command>),
+}
+
+// Both models follow the commands as they are read, so only
+// the blocks of `repeat`s that are still open are kept around.
+pub struct Day02 {
+    direct: Position,
+    aimed: Position,
+}
+
+impl Solution for Day02 {
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Day02, AocError> {
+        return Day02::parse_stream(&mut input.as_bytes());
+    }
+
+    fn parse_stream(reader: &mut dyn BufRead) -> Result<Day02, AocError> {
+        let mut direct = Submarine::new(Direct);
+        let mut aimed = Submarine::new(Aimed);
+
+        read_commands(reader, |command| {
+            direct.run(&command);
+            aimed.run(&command);
+        })?;
+
+        return Ok(Day02 {
+            direct: direct.position,
+            aimed: aimed.position,
+        });
+    }
+
+    fn part_1(&self) -> Result<i64, AocError> {
+        return solve_part_1(self);
+    }
+
+    fn part_2(&self) -> Result<i64, AocError> {
+        return solve_part_2(self);
+    }
+}
+
+// Follows the commands of a reader with any submarine,
+// for example one that records its trajectory.
+pub fn navigate<M: MovementModel>(reader: &mut dyn BufRead, mut submarine: Submarine<M>) -> Result<Submarine<M>, AocError> {
+    read_commands(reader, |command| submarine.run(&command))?;
+    return Ok(submarine);
+}
+
+// `repeat N {` opens a block that is closed by a line with only `}`,
+// the commands in between are collected on a stack. Commands outside
+// of any block are handed to `execute` as soon as they are complete.
+pub fn read_commands<F: FnMut(Command)>(reader: &mut dyn BufRead, mut execute: F) -> Result<(), AocError> {
+    let mut blocks: Vec<(usize, i64, Vec<Command>)> = vec![];
+
+    let mut lines = aoc::stream_lines(reader);
+    while let Some(line) = lines.next_line() {
+        let (number, line) = line?;
+        let command = match line.trim() {
+            "}" => match blocks.pop() {
+                Some((_, count, block)) => Command::Repeat(count, block),
+                None => return Err(AocError::parse(number, aoc::column_of(line, line.trim()), "No block to close")),
+            },
+            trimmed => match trimmed.strip_prefix("repeat ").and_then(|rest| rest.strip_suffix('{')) {
+                Some(count) => {
+                    let count = count.trim();
+                    let times: i64 = aoc::parse_token(number, line, count)?;
+                    if times < 0 {
+                        let message = format!("Expected a repeat count of 0 or more, found {}", times);
+                        return Err(AocError::parse(number, aoc::column_of(line, count), &message));
+                    }
+
+                    blocks.push((number, times, vec![]));
+                    continue;
+                },
+                None => parse_command(number, line)?,
+            },
+        };
+
+        match blocks.last_mut() {
+            Some((_, _, block)) => block.push(command),
+            None => execute(command),
+        };
+    }
+
+    if let Some((number, _, _)) = blocks.pop() {
+        return Err(AocError::parse(number, 1, "No closing `}` found"));
+    }
+
+    return Ok(());
+}
+
+fn parse_action(number: usize, line: &str, action: &str) -> Result<Action, AocError> {
+    return match action {
+        "up" => Ok(Action::Up),
+        "down" => Ok(Action::Down),
+        "forward" => Ok(Action::Forward),
+        "back" => Ok(Action::Back),
+        unknown => {
+            let message = format!("parse_action failed: {}", unknown);
+            Err(AocError::parse(number, aoc::column_of(line, action), &message))
+        },
+    };
+}
+
+fn parse_command(number: usize, line: &str) -> Result<Command, AocError> {
+    let mut parts = line.split_whitespace();
+
+    let action = match parts.next() {
+        Some("reset") => return Ok(Command::Reset),
+        Some(value) => parse_action(number, line, value)?,
+        None => return Err(aoc::missing_token(number, line, "No action found")),
+    };
+
+    return match parts.next() {
+        Some(value) => Ok(Command::Move(action, aoc::parse_token(number, line, value)?)),
+        None => Err(aoc::missing_token(number, line, "No amount found")),
+    };
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Position {
+    pub horizontal: i64,
+    pub depth: i64,
+    pub aim: i64,
+}
+
+// The two parts only differ in how a move changes the position,
+// so each is a model, and more can be plugged in the same way.
+pub trait MovementModel {
+    fn apply(&self, position: Position, action: Action, amount: i64) -> Position;
+}
+
+// Part 1: up and down change the depth directly.
+pub struct Direct;
+
+impl MovementModel for Direct {
+    fn apply(&self, mut position: Position, action: Action, amount: i64) -> Position {
+        match action {
+            Action::Up => position.depth -= amount,
+            Action::Down => position.depth += amount,
+            Action::Forward => position.horizontal += amount,
+            Action::Back => position.horizontal -= amount,
+        };
+
+        return position;
+    }
+}
+
+// Part 2: up and down change the aim, which
+// sets how deep each move forward or back goes.
+pub struct Aimed;
+
+impl MovementModel for Aimed {
+    fn apply(&self, mut position: Position, action: Action, amount: i64) -> Position {
+        match action {
+            Action::Up => position.aim -= amount,
+            Action::Down => position.aim += amount,
+            Action::Forward => {
+                position.horizontal += amount;
+                position.depth += position.aim * amount;
+            },
+            Action::Back => {
+                position.horizontal -= amount;
+                position.depth -= position.aim * amount;
+            },
+        };
+
+        return position;
+    }
+}
+
+// When created with `recording`, keeps every position it passes
+// through, starting with the origin. `new` only tracks the latest.
+pub struct Submarine<M> {
+    model: M,
+    pub position: Position,
+    trajectory: Option<Vec<Position>>,
+}
+
+impl<M: MovementModel> Submarine<M> {
+    pub fn new(model: M) -> Submarine<M> {
+        return Submarine {
+            model: model,
+            position: Position::default(),
+            trajectory: None,
+        };
+    }
+
+    pub fn recording(model: M) -> Submarine<M> {
+        return Submarine {
+            model: model,
+            position: Position::default(),
+            trajectory: Some(vec![Position::default()]),
+        };
+    }
+
+    pub fn execute(&mut self, commands: &[Command]) {
+        for command in commands {
+            self.run(command);
+        }
+    }
+
+    pub fn run(&mut self, command: &Command) {
+        match command {
+            Command::Move(action, amount) => {
+                self.position = self.model.apply(self.position, *action, *amount);
+            },
+            Command::Reset => {
+                self.position = Position::default();
+            },
+            Command::Repeat(count, block) => {
+                for _ in 0..*count {
+                    self.execute(block);
+                }
+
+                return;
+            },
+        };
+
+        if let Some(trajectory) = &mut self.trajectory {
+            trajectory.push(self.position);
+        }
+    }
+
+    // Empty unless the submarine was created with `recording`.
+    pub fn trajectory(&self) -> &[Position] {
+        return match &self.trajectory {
+            Some(trajectory) => trajectory,
+            None => &[],
+        };
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("step,horizontal,depth,aim\n");
+        for (step, position) in self.trajectory().iter().enumerate() {
+            csv.push_str(&format!("{},{},{},{}\n", step, position.horizontal, position.depth, position.aim));
+        }
+
+        return csv;
+    }
+}
+
+fn solve_part_1(day: &Day02) -> Result<i64, AocError> {
+    let position = day.direct;
+    return Ok(position.depth * position.horizontal);
+}
+
+fn solve_part_2(day: &Day02) -> Result<i64, AocError> {
+    let position = day.aimed;
+    return Ok(position.depth * position.horizontal);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+forward 5
+down 5
+forward 8
+up 3
+down 8
+forward 2";
+
+    #[test]
+    fn part_1_example() {
+        let day = Day02::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part_1().unwrap(), 150);
+    }
+
+    #[test]
+    fn part_2_example() {
+        let day = Day02::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part_2().unwrap(), 900);
+    }
+
+    #[test]
+    fn repeat_back_and_reset() {
+        let day = Day02::parse("forward 1\nreset\nrepeat 2 {\n  down 1\n  repeat 3 {\n    forward 2\n  }\n  back 1\n}\n").unwrap();
+        assert_eq!(day.direct, Position { horizontal: 10, depth: 2, aim: 0 });
+        assert_eq!(day.aimed, Position { horizontal: 10, depth: 15, aim: 2 });
+    }
+
+    #[test]
+    fn unbalanced_blocks() {
+        let error = Day02::parse("repeat 2 {\nforward 1").err().unwrap();
+        assert_eq!(error.to_string(), "line 1, column 1: No closing `}` found");
+
+        let error = Day02::parse("forward 1\n}").err().unwrap();
+        assert_eq!(error.to_string(), "line 2, column 1: No block to close");
+
+        let error = Day02::parse("repeat -2 {\nforward 1\n}").err().unwrap();
+        assert_eq!(error.to_string(), "line 1, column 8: Expected a repeat count of 0 or more, found -2");
+    }
+
+    #[test]
+    fn trajectory_to_csv() {
+        let submarine = navigate(&mut EXAMPLE.as_bytes(), Submarine::new(Aimed)).unwrap();
+        assert!(submarine.trajectory().is_empty());
+
+        let submarine = navigate(&mut EXAMPLE.as_bytes(), Submarine::recording(Aimed)).unwrap();
+        assert_eq!(submarine.trajectory().len(), 7);
+        assert!(submarine.to_csv().starts_with("step,horizontal,depth,aim\n0,0,0,0\n1,5,0,0\n2,5,0,5\n3,13,40,5\n"));
+    }
+}
@@ -0,0 +1,328 @@
+use crate as aoc;
+use crate::AocError;
+use crate::Solution;
+
+use std::io::BufRead;
+
+pub struct Day03 {
+    report: DiagnosticReport,
+}
+
+impl Solution for Day03 {
+    type Part1 = u128;
+    type Part2 = u128;
+
+    fn parse(input: &str) -> Result<Day03, AocError> {
+        return Day03::parse_stream(&mut input.as_bytes());
+    }
+
+    fn parse_stream(reader: &mut dyn BufRead) -> Result<Day03, AocError> {
+        let mut report = DiagnosticReport {
+            width: 0,
+            numbers: vec![],
+            ones: vec![],
+        };
+
+        let mut lines = aoc::stream_lines(reader);
+        while let Some(line) = lines.next_line() {
+            let (number, line) = line?;
+            if report.numbers.is_empty() {
+                report.width = line.len();
+                report.ones = vec![0; line.len()];
+            } else if line.len() != report.width {
+                let message = format!("Expected a row of {} bits", report.width);
+                return Err(aoc::missing_token(number, line, &message));
+            }
+
+            let bits = BitSet::parse(number, line)?;
+            for (column, ones) in report.ones.iter_mut().enumerate() {
+                if bits.get(column) {
+                    *ones += 1;
+                }
+            }
+
+            report.numbers.push(bits);
+        }
+
+        return Ok(Day03 {
+            report: report,
+        });
+    }
+
+    fn part_1(&self) -> Result<u128, AocError> {
+        return solve_part_1(self);
+    }
+
+    fn part_2(&self) -> Result<u128, AocError> {
+        return solve_part_2(self);
+    }
+}
+
+impl Day03 {
+    pub fn report(&self) -> &DiagnosticReport {
+        return &self.report;
+    }
+}
+
+// A row of bits of any width. Column 0 is the leftmost
+// bit, which is the most significant one as a number.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BitSet {
+    width: usize,
+    words: Vec<u64>,
+}
+
+impl BitSet {
+    pub fn new(width: usize) -> BitSet {
+        return BitSet {
+            width: width,
+            words: vec![0; width.div_ceil(64)],
+        };
+    }
+
+    pub fn parse(number: usize, line: &str) -> Result<BitSet, AocError> {
+        let mut bits = BitSet::new(line.len());
+        for (column, ch) in line.char_indices() {
+            match ch {
+                '0' => {},
+                '1' => bits.set(column, true),
+                _ => {
+                    let message = format!("Unknown symbol found: `{}`", ch);
+                    return Err(AocError::parse(number, column + 1, &message));
+                },
+            };
+        }
+
+        return Ok(bits);
+    }
+
+    pub fn width(&self) -> usize {
+        return self.width;
+    }
+
+    pub fn get(&self, column: usize) -> bool {
+        return (self.words[column / 64] >> (column % 64)) & 1 == 1;
+    }
+
+    pub fn set(&mut self, column: usize, value: bool) {
+        let mask = 1 << (column % 64);
+        match value {
+            true => self.words[column / 64] |= mask,
+            false => self.words[column / 64] &= !mask,
+        };
+    }
+
+    // None if the value does not fit, which only
+    // happens for rows wider than 128 bits.
+    pub fn to_u128(&self) -> Option<u128> {
+        let mut value: u128 = 0;
+        for column in 0..self.width {
+            if value >> 127 != 0 {
+                return None;
+            }
+
+            value = (value << 1) | (self.get(column) as u128);
+        }
+
+        return Some(value);
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Statistic {
+    MostCommon,
+    LeastCommon,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BitOrder {
+    MostSignificantFirst,
+    LeastSignificantFirst,
+}
+
+// Which bit to keep in each column: the bit picked by the
+// statistic, or `tie_break` when 0 and 1 are equally common.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Criteria {
+    pub statistic: Statistic,
+    pub tie_break: bool,
+    pub order: BitOrder,
+}
+
+pub const OXYGEN_GENERATOR: Criteria = Criteria {
+    statistic: Statistic::MostCommon,
+    tie_break: true,
+    order: BitOrder::MostSignificantFirst,
+};
+
+pub const CO2_SCRUBBER: Criteria = Criteria {
+    statistic: Statistic::LeastCommon,
+    tie_break: false,
+    order: BitOrder::MostSignificantFirst,
+};
+
+impl Criteria {
+    pub fn select(&self, ones: usize, total: usize) -> bool {
+        let zeros = total - ones;
+        if ones == zeros {
+            return self.tie_break;
+        }
+
+        return match self.statistic {
+            Statistic::MostCommon => ones > zeros,
+            Statistic::LeastCommon => ones < zeros,
+        };
+    }
+
+    fn columns(&self, width: usize) -> Vec<usize> {
+        return match self.order {
+            BitOrder::MostSignificantFirst => (0..width).collect(),
+            BitOrder::LeastSignificantFirst => (0..width).rev().collect(),
+        };
+    }
+}
+
+// The ones in each column are counted once while parsing,
+// so the rates never have to look at the numbers again.
+pub struct DiagnosticReport {
+    width: usize,
+    numbers: Vec<BitSet>,
+    ones: Vec<usize>,
+}
+
+impl DiagnosticReport {
+    pub fn width(&self) -> usize {
+        return self.width;
+    }
+
+    pub fn ones(&self) -> &[usize] {
+        return &self.ones;
+    }
+
+    // The bit order does not matter here, every column is used.
+    pub fn rate(&self, criteria: &Criteria) -> BitSet {
+        let mut rate = BitSet::new(self.width);
+        for (column, ones) in self.ones.iter().enumerate() {
+            rate.set(column, criteria.select(*ones, self.numbers.len()));
+        }
+
+        return rate;
+    }
+
+    // Narrows the numbers down column by column until one is
+    // left. Duplicates that survive every column give the first.
+    pub fn rating(&self, criteria: &Criteria) -> Option<&BitSet> {
+        let mut candidates: Vec<&BitSet> = self.numbers.iter().collect();
+        for column in criteria.columns(self.width) {
+            if candidates.len() <= 1 {
+                break;
+            }
+
+            let ones = candidates.iter().filter(|bits| bits.get(column)).count();
+            let keep = criteria.select(ones, candidates.len());
+            candidates.retain(|bits| bits.get(column) == keep);
+        }
+
+        return candidates.first().copied();
+    }
+}
+
+fn product(first: &BitSet, second: &BitSet) -> Result<u128, AocError> {
+    return match (first.to_u128(), second.to_u128()) {
+        (Some(first), Some(second)) => first.checked_mul(second).ok_or(AocError::NoSolution),
+        _ => Err(AocError::NoSolution),
+    };
+}
+
+fn solve_part_1(day: &Day03) -> Result<u128, AocError> {
+    // The rates pick the same bits as the ratings do,
+    // only from every number instead of the survivors.
+    let gamma_rate = day.report.rate(&OXYGEN_GENERATOR);
+    let epsilon_rate = day.report.rate(&CO2_SCRUBBER);
+    return product(&gamma_rate, &epsilon_rate);
+}
+
+fn solve_part_2(day: &Day03) -> Result<u128, AocError> {
+    let ratings = (day.report.rating(&OXYGEN_GENERATOR), day.report.rating(&CO2_SCRUBBER));
+    return match ratings {
+        (Some(oxygen_generator_rating), Some(carbon_dioxide_scrubber_rating)) => {
+            product(oxygen_generator_rating, carbon_dioxide_scrubber_rating)
+        },
+        _ => Err(AocError::NoSolution),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Answer;
+
+    const EXAMPLE: &str = "\
+00100
+11110
+10110
+10111
+10101
+01111
+00111
+11100
+10000
+11001
+00010
+01010";
+
+    #[test]
+    fn part_1_example() {
+        let day = Day03::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part_1().unwrap(), 198);
+    }
+
+    #[test]
+    fn wide_answers_stay_json_numbers() {
+        // The parts return `u128` so products of wide reports
+        // fit, which must not change the output format.
+        let day = Day03::parse(EXAMPLE).unwrap();
+        assert_eq!(Answer::from(day.part_1().unwrap()).to_json(), "198");
+        assert_eq!(Answer::from(day.part_2().unwrap()).to_json(), "230");
+    }
+
+    #[test]
+    fn part_2_example() {
+        let day = Day03::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part_2().unwrap(), 230);
+    }
+
+    #[test]
+    fn counts_per_column() {
+        let day = Day03::parse(EXAMPLE).unwrap();
+        assert_eq!(day.report().ones(), [7, 5, 8, 7, 5]);
+    }
+
+    #[test]
+    fn criteria_as_data() {
+        let day = Day03::parse(EXAMPLE).unwrap();
+        let criteria = Criteria {
+            statistic: Statistic::MostCommon,
+            tie_break: true,
+            order: BitOrder::LeastSignificantFirst,
+        };
+
+        // Rightmost column first: 5 of 12 are ones, so keep 0, then
+        // 4 of 7 ones keeps 1, and two ties keep 1 until one is left.
+        assert_eq!(day.report().rating(&criteria).and_then(BitSet::to_u128), Some(0b11110));
+    }
+
+    #[test]
+    fn wider_than_64_bits() {
+        let line = format!("1{}1", "0".repeat(98));
+        let bits = BitSet::parse(1, &line).unwrap();
+
+        assert_eq!(bits.width(), 100);
+        assert!(bits.get(0) && bits.get(99) && !bits.get(64));
+        assert_eq!(bits.to_u128(), Some((1 << 99) | 1));
+        assert_eq!(BitSet::parse(1, &"1".repeat(129)).unwrap().to_u128(), None);
+
+        let error = Day03::parse("0101\n010").err().unwrap();
+        assert_eq!(error.to_string(), "line 2, column 4: Expected a row of 4 bits");
+    }
+}
@@ -0,0 +1,334 @@
+use crate as aoc;
+use crate::grid::Point;
+use crate::AocError;
+use crate::Grid;
+use crate::Solution;
+
+use std::collections::HashMap;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WinRule {
+    Rows,
+    Columns,
+    // Only square boards have diagonals.
+    Diagonals,
+    FullCard,
+    FourCorners,
+}
+
+pub const STANDARD_RULES: [WinRule; 2] = [WinRule::Rows, WinRule::Columns];
+
+// Keeps a count of the marked cells per row, column and so on, so
+// marking a number and checking for a win both take constant time.
+#[derive(Clone)]
+pub struct Board {
+    values: Grid<i64>,
+    marked: Grid<bool>,
+    positions: HashMap<i64, Vec<Point>>,
+    row_marks: Vec<usize>,
+    column_marks: Vec<usize>,
+    diagonal_marks: [usize; 2],
+    corner_marks: usize,
+    corners: usize,
+    total_marks: usize,
+    complete_rows: usize,
+    complete_columns: usize,
+    unmarked_sum: i64,
+}
+
+impl Board {
+    pub fn new(values: Grid<i64>) -> Board {
+        let mut positions: HashMap<i64, Vec<Point>> = HashMap::new();
+        for point in values.points() {
+            positions.entry(values[point]).or_default().push(point);
+        }
+
+        // Boards of a single row or column have fewer distinct corners.
+        let corners = [values.width, values.height].iter().map(|side| (*side).min(2)).product();
+
+        return Board {
+            marked: Grid::new(values.width, values.height, false),
+            positions: positions,
+            row_marks: vec![0; values.height],
+            column_marks: vec![0; values.width],
+            diagonal_marks: [0; 2],
+            corner_marks: 0,
+            corners: corners,
+            total_marks: 0,
+            complete_rows: 0,
+            complete_columns: 0,
+            unmarked_sum: values.iter().sum(),
+            values: values,
+        };
+    }
+
+    pub fn width(&self) -> usize {
+        return self.values.width;
+    }
+
+    pub fn height(&self) -> usize {
+        return self.values.height;
+    }
+
+    pub fn is_marked(&self, point: Point) -> bool {
+        return self.marked[point];
+    }
+
+    pub fn mark(&mut self, called: i64) {
+        // A number is only ever marked once, so
+        // it can be dropped from the index.
+        let points = match self.positions.remove(&called) {
+            Some(value) => value,
+            None => return,
+        };
+
+        for (x, y) in points {
+            self.marked[(x, y)] = true;
+            self.unmarked_sum -= called;
+            self.total_marks += 1;
+
+            self.row_marks[y] += 1;
+            if self.row_marks[y] == self.width() {
+                self.complete_rows += 1;
+            }
+
+            self.column_marks[x] += 1;
+            if self.column_marks[x] == self.height() {
+                self.complete_columns += 1;
+            }
+
+            if self.width() == self.height() {
+                if x == y {
+                    self.diagonal_marks[0] += 1;
+                }
+
+                if x + y + 1 == self.width() {
+                    self.diagonal_marks[1] += 1;
+                }
+            }
+
+            let last = (self.width() - 1, self.height() - 1);
+            if (x == 0 || x == last.0) && (y == 0 || y == last.1) {
+                self.corner_marks += 1;
+            }
+        }
+    }
+
+    pub fn has_won(&self, rules: &[WinRule]) -> bool {
+        let cells = self.width() * self.height();
+        return rules.iter().any(|rule| match rule {
+            WinRule::Rows => self.complete_rows > 0,
+            WinRule::Columns => self.complete_columns > 0,
+            WinRule::Diagonals => self.width() == self.height() && self.diagonal_marks.contains(&self.width()),
+            WinRule::FullCard => self.total_marks == cells,
+            WinRule::FourCorners => self.corner_marks == self.corners,
+        });
+    }
+
+    pub fn score(&self, just_called: i64) -> i64 {
+        return self.unmarked_sum * just_called;
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Win {
+    pub board: usize,
+    pub call: usize,
+    pub score: i64,
+}
+
+#[derive(Clone)]
+pub struct Day04 {
+    called: Vec<i64>,
+    boards: Vec<Board>,
+}
+
+impl Solution for Day04 {
+    type Part1 = i64;
+    type Part2 = i64;
+
+    // Boards are separated by blank lines, and can be of any
+    // size as long as every row of a board is equally wide.
+    fn parse(input: &str) -> Result<Day04, AocError> {
+        let mut day = Day04 {
+            called: vec![],
+            boards: vec![],
+        };
+
+        let mut lines = aoc::numbered_lines(input);
+        let (number, line) = match lines.next() {
+            Some(value) => value,
+            None => return Err(aoc::missing_token(1, "", "No called numbers found")),
+        };
+
+        for called in line.split(',') {
+            day.called.push(aoc::parse_token(number, line, called.trim())?);
+        }
+
+        // The extra blank line at the end closes the last board.
+        let mut rows: Vec<Vec<i64>> = vec![];
+        for (number, line) in lines.chain([(0, "")]) {
+            if line.trim().is_empty() {
+                if !rows.is_empty() {
+                    let values = Grid::from_fn(rows[0].len(), rows.len(), |(x, y)| rows[y][x]);
+                    day.boards.push(Board::new(values));
+                    rows.clear();
+                }
+
+                continue;
+            }
+
+            let mut row = vec![];
+            for cell in line.split_whitespace() {
+                row.push(aoc::parse_token(number, line, cell)?);
+            }
+
+            if let Some(first) = rows.first() {
+                if row.len() != first.len() {
+                    let message = format!("Expected a row of {} numbers", first.len());
+                    return Err(aoc::missing_token(number, line, &message));
+                }
+            }
+
+            rows.push(row);
+        }
+
+        return Ok(day);
+    }
+
+    fn part_1(&self) -> Result<i64, AocError> {
+        return solve_part_1(self);
+    }
+
+    fn part_2(&self) -> Result<i64, AocError> {
+        return solve_part_2(self);
+    }
+}
+
+impl Day04 {
+    // Every board that wins, in the order they win. Boards that
+    // win on the same call are listed in the order they were given.
+    pub fn play(&self, rules: &[WinRule]) -> Vec<Win> {
+        let mut boards = self.boards.clone();
+        let mut wins = vec![];
+
+        for (call, called) in self.called.iter().enumerate() {
+            for (index, board) in boards.iter_mut().enumerate() {
+                if board.has_won(rules) {
+                    continue;
+                }
+
+                board.mark(*called);
+                if board.has_won(rules) {
+                    wins.push(Win {
+                        board: index,
+                        call: call,
+                        score: board.score(*called),
+                    });
+                }
+            }
+        }
+
+        return wins;
+    }
+}
+
+fn solve_part_1(day: &Day04) -> Result<i64, AocError> {
+    return match day.play(&STANDARD_RULES).first() {
+        Some(win) => Ok(win.score),
+        None => Err(AocError::NoSolution),
+    };
+}
+
+fn solve_part_2(day: &Day04) -> Result<i64, AocError> {
+    return match day.play(&STANDARD_RULES).last() {
+        Some(win) => Ok(win.score),
+        None => Err(AocError::NoSolution),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+
+ 3 15  0  2 22
+ 9 18 13 17  5
+19  8  7 25 23
+20 11 10 24  4
+14 21 16 12  6
+
+14 21 17 24  4
+10 16 15  9 19
+18  8 23 26 20
+22 11 13  6  5
+ 2  0 12  3  7";
+
+    #[test]
+    fn part_1_example() {
+        let day = Day04::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part_1().unwrap(), 4512);
+    }
+
+    #[test]
+    fn part_2_example() {
+        let day = Day04::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part_2().unwrap(), 1924);
+    }
+
+    #[test]
+    fn every_win_in_order() {
+        let day = Day04::parse(EXAMPLE).unwrap();
+        let wins = day.play(&STANDARD_RULES);
+
+        assert_eq!(wins.len(), 3);
+        assert_eq!(wins[0], Win { board: 2, call: 11, score: 4512 });
+        assert_eq!(wins[2], Win { board: 1, call: 14, score: 1924 });
+    }
+
+    const SMALL_BOARDS: &str = "\
+1,5,9,3,7
+
+1 2 3
+4 5 6
+7 8 9
+
+1 3
+5 7
+9 2";
+
+    #[test]
+    fn configurable_win_rules() {
+        let day = Day04::parse(SMALL_BOARDS).unwrap();
+        assert_eq!((day.boards[1].width(), day.boards[1].height()), (2, 3));
+
+        assert_eq!(day.play(&[WinRule::Diagonals]), [Win { board: 0, call: 2, score: 270 }]);
+        assert_eq!(day.play(&[WinRule::Rows, WinRule::FourCorners]), [
+            Win { board: 1, call: 3, score: 27 },
+            Win { board: 0, call: 4, score: 140 },
+        ]);
+        assert!(day.play(&[WinRule::FullCard]).is_empty());
+    }
+
+    #[test]
+    fn marking_by_value() {
+        let mut board = Day04::parse(SMALL_BOARDS).unwrap().boards[0].clone();
+        board.mark(5);
+        board.mark(42);
+
+        assert!(board.is_marked((1, 1)));
+        assert!(!board.is_marked((0, 0)));
+        assert_eq!(board.score(1), 40);
+
+        let error = Day04::parse("1,2\n\n1 2\n3").err().unwrap();
+        assert_eq!(error.to_string(), "line 4, column 2: Expected a row of 2 numbers");
+    }
+}
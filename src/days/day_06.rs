@@ -0,0 +1,100 @@
+use crate as aoc;
+use crate::AocError;
+use crate::Solution;
+
+#[derive(Clone)]
+pub struct Day06 {
+    timers: Vec<i64>,
+}
+
+impl Solution for Day06 {
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Day06, AocError> {
+        let mut day = Day06 {
+            timers: vec![],
+        };
+
+        for (number, line) in aoc::numbered_lines(input) {
+            for timer in line.split(",") {
+                let value = aoc::parse_token(number, line, timer)?;
+
+                // The histogram in part 2 only has room
+                // for the timer values a lanternfish can have.
+                if !(0..=8).contains(&value) {
+                    let column = aoc::column_of(line, timer);
+                    return Err(AocError::parse(number, column, "Timer out of range"));
+                }
+
+                day.timers.push(value);
+            }
+        }
+
+        return Ok(day);
+    }
+
+    fn part_1(&self) -> Result<i64, AocError> {
+        return solve_part_1(self);
+    }
+
+    fn part_2(&self) -> Result<i64, AocError> {
+        return solve_part_2(self);
+    }
+}
+
+fn solve_part_1(day: &Day06) -> Result<i64, AocError> {
+    let mut clone = day.clone();
+
+    for _ in 0..80 {
+        for i in 0..clone.timers.len() {
+            clone.timers[i] -= 1;
+
+            if clone.timers[i] < 0 {
+                clone.timers[i] = 6;
+                clone.timers.push(8);
+            }
+        }
+    }
+
+    return Ok(clone.timers.len() as i64);
+}
+
+fn solve_part_2(day: &Day06) -> Result<i64, AocError> {
+    let mut histogram = [0; 9];
+    for timer in &day.timers {
+        histogram[*timer as usize] += 1;
+    }
+
+    for _ in 0..256 {
+        histogram.rotate_left(1);
+        histogram[6] += histogram[8];
+    }
+
+    let mut num_lanternfish = 0;
+    for lanternfish in histogram {
+        num_lanternfish += lanternfish;
+    }
+
+    return Ok(num_lanternfish);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+3,4,3,1,2";
+
+    #[test]
+    fn part_1_example() {
+        let day = Day06::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part_1().unwrap(), 5934);
+    }
+
+    #[test]
+    fn part_2_example() {
+        let day = Day06::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part_2().unwrap(), 26984457539);
+    }
+}
@@ -0,0 +1,112 @@
+use crate as aoc;
+use crate::AocError;
+use crate::Solution;
+
+use itertools::sorted;
+use std::cmp::min;
+
+pub struct Day07 {
+    positions: Vec<i64>,
+}
+
+impl Solution for Day07 {
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Day07, AocError> {
+        let mut day = Day07 {
+            positions: vec![],
+        };
+
+        for (number, line) in aoc::numbered_lines(input) {
+            for position in line.split(",") {
+                day.positions.push(aoc::parse_token(number, line, position)?);
+            }
+        }
+
+        return Ok(day);
+    }
+
+    fn part_1(&self) -> Result<i64, AocError> {
+        return solve_part_1(self);
+    }
+
+    fn part_2(&self) -> Result<i64, AocError> {
+        return solve_part_2(self);
+    }
+}
+
+fn mean(vec: &Vec<i64>) -> Result<i64, AocError> {
+    if vec.is_empty() {
+        return Err(AocError::NoSolution);
+    }
+
+    let mut sum = 0;
+    for value in vec {
+        sum += value;
+    }
+
+    return Ok(sum / vec.len() as i64);
+}
+
+fn median(vec: &Vec<i64>) -> Result<i64, AocError> {
+    let mut s = sorted(vec);
+    return match s.nth(s.len() / 2) {
+        Some(value) => Ok(*value),
+        None => Err(AocError::NoSolution),
+    };
+}
+
+fn solve_part_1(day: &Day07) -> Result<i64, AocError> {
+    let target = median(&day.positions)?;
+
+    let mut fuel = 0;
+    for position in &day.positions {
+        fuel += (position - target).abs();
+    }
+
+    return Ok(fuel);
+}
+
+fn triangular_fuel(positions: &Vec<i64>, target: i64) -> i64 {
+    let mut fuel = 0;
+    for position in positions {
+        let distance = (position - target).abs();
+        fuel += distance * (distance + 1) / 2;
+    }
+
+    return fuel;
+}
+
+fn solve_part_2(day: &Day07) -> Result<i64, AocError> {
+    let target = mean(&day.positions)?;
+
+    // The best position is within 0.5 of the mean, but the
+    // integer mean is truncated, so also try the next one.
+    let fuel = min(
+        triangular_fuel(&day.positions, target),
+        triangular_fuel(&day.positions, target + 1),
+    );
+
+    return Ok(fuel);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+16,1,2,0,4,2,7,1,2,14";
+
+    #[test]
+    fn part_1_example() {
+        let day = Day07::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part_1().unwrap(), 37);
+    }
+
+    #[test]
+    fn part_2_example() {
+        let day = Day07::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part_2().unwrap(), 168);
+    }
+}
@@ -0,0 +1,180 @@
+use crate as aoc;
+use crate::AocError;
+use crate::Solution;
+
+use itertools::sorted;
+use std::collections::HashMap;
+
+struct Signal {
+    patterns: Vec<String>,
+    output_values: Vec<String>,
+}
+
+pub struct Day08 {
+    signals: Vec<Signal>,
+}
+
+impl Solution for Day08 {
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Day08, AocError> {
+        let mut day = Day08 {
+            signals: vec![],
+        };
+
+        for (number, line) in aoc::numbered_lines(input) {
+            day.signals.push(parse_signal(number, line)?);
+        }
+
+        return Ok(day);
+    }
+
+    fn part_1(&self) -> Result<i64, AocError> {
+        return solve_part_1(self);
+    }
+
+    fn part_2(&self) -> Result<i64, AocError> {
+        return solve_part_2(self);
+    }
+}
+
+fn parse_segments(segments: &str) -> Vec<String> {
+    let parts = segments.split(" ");
+
+    let mut values = vec![];
+    for value in parts {
+        let chars = sorted(value.chars());
+        values.push(chars.collect());
+    }
+
+    return values;
+}
+
+fn parse_signal(number: usize, line: &str) -> Result<Signal, AocError> {
+    let mut parts = line.split(" | ");
+
+    let signal = Signal {
+        patterns: match parts.next() {
+            Some(value) => parse_segments(value),
+            None => return Err(aoc::missing_token(number, line, "No signal patterns found")),
+        },
+        output_values: match parts.next() {
+            Some(value) => parse_segments(value),
+            None => return Err(aoc::missing_token(number, line, "No output values found")),
+        },
+    };
+
+    // Part 2 deduces the digits from
+    // all ten unique signal patterns.
+    if signal.patterns.len() != 10 {
+        return Err(AocError::parse(number, 1, "Expected 10 signal patterns"));
+    }
+
+    return Ok(signal);
+}
+
+fn solve_part_1(day: &Day08) -> Result<i64, AocError> {
+    let output_value_lengths = [2, 4, 3, 7];
+
+    let mut count = 0;
+    for signal in &day.signals {
+        for output_value in &signal.output_values {
+            let length = output_value.len() as i64;
+            if output_value_lengths.contains(&length) {
+                count += 1;
+            }
+        }
+    }
+
+    return Ok(count);
+}
+
+fn except(minuend: &str, subtrahend: &str) -> String {
+    let mut difference = String::new();
+    for segment in minuend.chars() {
+        if !subtrahend.contains(segment) {
+            difference.push(segment);
+        }
+    }
+
+    return difference;
+}
+
+fn solve_part_2(day: &Day08) -> Result<i64, AocError> {
+    let mut sum = 0;
+    for signal in &day.signals {
+        let mut patterns = signal.patterns.clone();
+        patterns.sort_by_key(|pattern| pattern.len());
+
+        let mut digits = HashMap::<&String, i64>::new();
+
+        digits.insert(&patterns[0], 1);
+        digits.insert(&patterns[2], 4);
+        digits.insert(&patterns[1], 7);
+        digits.insert(&patterns[9], 8);
+
+        for pattern in &patterns {
+            let minus_1 = except(pattern, &patterns[0]);
+            let minus_4 = except(pattern, &patterns[2]);
+
+            if pattern.len() == 5 {
+                match (minus_1.len(), minus_4.len()) {
+                    (4, 3) => digits.insert(pattern, 2),
+                    (3, 2) => digits.insert(pattern, 3),
+                    (4, 2) => digits.insert(pattern, 5),
+                    _ => return Err(AocError::NoSolution),
+                };
+            } else if pattern.len() == 6 {
+                match (minus_1.len(), minus_4.len()) {
+                    (4, 3) => digits.insert(pattern, 0),
+                    (5, 3) => digits.insert(pattern, 6),
+                    (4, 2) => digits.insert(pattern, 9),
+                    _ => return Err(AocError::NoSolution),
+                };
+            }
+        }
+
+        let mut partial_sum = 0;
+        for output_value in &signal.output_values {
+            partial_sum *= 10;
+            partial_sum += match digits.get(output_value) {
+                Some(value) => value,
+                None => return Err(AocError::NoSolution),
+            };
+        }
+
+        sum += partial_sum;
+    }
+
+    return Ok(sum);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
+edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
+fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
+fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
+aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea
+fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb
+dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe
+bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
+egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
+gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce";
+
+    #[test]
+    fn part_1_example() {
+        let day = Day08::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part_1().unwrap(), 26);
+    }
+
+    #[test]
+    fn part_2_example() {
+        let day = Day08::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part_2().unwrap(), 61229);
+    }
+}
@@ -0,0 +1,137 @@
+use crate::grid::Point;
+use crate::search;
+use crate::search::SearchSpace;
+use crate::AocError;
+use crate::Grid;
+use crate::Solution;
+
+struct Heightmap {
+    elevations: Grid<i64>,
+}
+
+impl Heightmap {
+    fn at(&self, point: Point) -> i64 {
+        return self.elevations[point];
+    }
+
+    fn is_lower_than(&self, point_1: Point, point_2: Point) -> bool {
+        return self.at(point_1) < self.at(point_2);
+    }
+
+    fn is_low_point(&self, point: Point) -> bool {
+        for neighbor in self.elevations.neighbors_4(point) {
+            if !self.is_lower_than(point, neighbor) {
+                return false;
+            }
+        }
+
+        return true;
+    }
+}
+
+// A basin spreads uphill from its low point,
+// up to but not including the 9s around it.
+impl SearchSpace for Heightmap {
+    type State = Point;
+
+    fn neighbors(&self, cell: &Point) -> Vec<(Point, i64)> {
+        let mut neighbors = vec![];
+        for neighbor in self.elevations.neighbors_4(*cell) {
+            if self.is_lower_than(*cell, neighbor) && self.at(neighbor) < 9 {
+                neighbors.push((neighbor, 1));
+            }
+        }
+
+        return neighbors;
+    }
+
+    fn is_goal(&self, _cell: &Point) -> bool {
+        return false;
+    }
+}
+
+pub struct Day09 {
+    heightmap: Heightmap,
+}
+
+impl Solution for Day09 {
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Day09, AocError> {
+        let elevations = Grid::parse(input, |ch| ch.to_digit(10).map(i64::from))?;
+
+        return Ok(Day09 {
+            heightmap: Heightmap {
+                elevations: elevations,
+            },
+        });
+    }
+
+    fn part_1(&self) -> Result<i64, AocError> {
+        return solve_part_1(self);
+    }
+
+    fn part_2(&self) -> Result<i64, AocError> {
+        return solve_part_2(self);
+    }
+}
+
+fn solve_part_1(day: &Day09) -> Result<i64, AocError> {
+    let mut risk_level = 0;
+    for point in day.heightmap.elevations.points() {
+        if day.heightmap.is_low_point(point) {
+            risk_level += day.heightmap.at(point) + 1;
+        }
+    }
+
+    return Ok(risk_level);
+}
+
+fn solve_part_2(day: &Day09) -> Result<i64, AocError> {
+    let mut basins = vec![];
+    for point in day.heightmap.elevations.points() {
+        if day.heightmap.is_low_point(point) {
+            basins.push(search::reachable(&day.heightmap, point).len());
+        }
+    }
+
+    if basins.len() < 3 {
+        return Err(AocError::NoSolution);
+    }
+
+    basins.sort();
+
+    let n = basins.len() - 1;
+    return Ok((basins[n] * basins[n - 1] * basins[n - 2]) as i64);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+2199943210
+3987894921
+9856789892
+8767896789
+9899965678";
+
+    #[test]
+    fn part_1_example() {
+        let day = Day09::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part_1().unwrap(), 15);
+    }
+
+    #[test]
+    fn part_2_example() {
+        let day = Day09::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part_2().unwrap(), 1134);
+    }
+
+    #[test]
+    fn fewer_than_three_basins() {
+        let day = Day09::parse("19\n91").unwrap();
+        assert!(matches!(day.part_2(), Err(AocError::NoSolution)));
+    }
+}
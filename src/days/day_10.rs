@@ -0,0 +1,185 @@
+use crate as aoc;
+use crate::AocError;
+use crate::Solution;
+
+use std::collections::HashMap;
+use std::io::BufRead;
+
+// Each line is scored as it is read, so only the scores have to
+// be kept around. This means `bench` counts the solving as parse.
+pub struct Day10 {
+    error_score: i64,
+    autocomplete_scores: Vec<i64>,
+}
+
+impl Solution for Day10 {
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Day10, AocError> {
+        return Day10::parse_stream(&mut input.as_bytes());
+    }
+
+    fn parse_stream(reader: &mut dyn BufRead) -> Result<Day10, AocError> {
+        let mut day = Day10 {
+            error_score: 0,
+            autocomplete_scores: vec![],
+        };
+
+        let mut lines = aoc::stream_lines(reader);
+        while let Some(line) = lines.next_line() {
+            let (number, line) = line?;
+            if let Some(column) = line.find(|symbol| !"()[]{}<>".contains(symbol)) {
+                let message = format!("Unknown symbol found: `{}`", &line[column..]);
+                return Err(AocError::parse(number, column + 1, &message));
+            }
+
+            match determine_status(line) {
+                Status::Complete => {},
+                Status::Corrupted(symbol) => {
+                    day.error_score += error_score_for(symbol);
+                },
+                Status::Incomplete(ref mut stack) => {
+                    day.autocomplete_scores.push(autocomplete_score_for(stack));
+                },
+            };
+        }
+
+        return Ok(day);
+    }
+
+    fn part_1(&self) -> Result<i64, AocError> {
+        return solve_part_1(self);
+    }
+
+    fn part_2(&self) -> Result<i64, AocError> {
+        return solve_part_2(self);
+    }
+}
+
+enum Status {
+    Complete,
+    Corrupted(char),
+    Incomplete(Vec<char>),
+}
+
+fn is_open(symbol: char) -> bool {
+    return symbol == '('
+        || symbol == '['
+        || symbol == '{'
+        || symbol == '<';
+}
+
+fn is_close_for(open: char, close: char) -> bool {
+    return (open == '(' && close == ')')
+        || (open == '[' && close == ']')
+        || (open == '{' && close == '}')
+        || (open == '<' && close == '>');
+}
+
+fn determine_status(line: &str) -> Status {
+    let mut stack = vec![];
+    for symbol in line.chars() {
+        if is_open(symbol) {
+            stack.push(symbol);
+        } else {
+            // A close with nothing left open
+            // is just as illegal as a mismatch.
+            let open = match stack.pop() {
+                Some(value) => value,
+                None => return Status::Corrupted(symbol),
+            };
+
+            if !is_close_for(open, symbol) {
+                return Status::Corrupted(symbol);
+            }
+        }
+    }
+
+    if !stack.is_empty() {
+        return Status::Incomplete(stack);
+    }
+    
+    return Status::Complete;
+}
+
+fn error_score_for(symbol: char) -> i64 {
+    let scores = HashMap::from([
+        (')', 3), (']', 57), ('}', 1197), ('>', 25137),
+    ]);
+
+    return match scores.get(&symbol) {
+        Some(value) => *value,
+        None => panic!("Unknown symbol found: `{}`", symbol),
+    };
+}
+
+fn solve_part_1(day: &Day10) -> Result<i64, AocError> {
+    return Ok(day.error_score);
+}
+
+fn bump(score: &mut i64, symbol: char) {
+    // Skip finding the matching close character,
+    // score based on the open characters directly.
+    let scores = HashMap::from([
+        ('(', 1), ('[', 2), ('{', 3), ('<', 4),
+    ]);
+
+    *score *= 5;
+    *score += match scores.get(&symbol) {
+        Some(value) => *value,
+        None => panic!("Unknown symbol found: `{}`", symbol),
+    };
+}
+
+fn autocomplete_score_for(stack: &mut Vec<char>) -> i64 {
+    let mut score = 0;
+    while !stack.is_empty() {
+        match stack.pop() {
+            Some(symbol) => bump(&mut score, symbol),
+            None => panic!("Tried to pop, but no value found"),
+        };
+    }
+
+    return score;
+}
+
+fn solve_part_2(day: &Day10) -> Result<i64, AocError> {
+    let mut scores = day.autocomplete_scores.clone();
+    scores.sort();
+
+    let n = scores.len();
+    return match scores.get(n / 2) {
+        Some(value) => Ok(*value),
+        None => Err(AocError::NoSolution),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+[({(<(())[]>[[{[]{<()<>>
+[(()[<>])]({[<{<<[]>>(
+{([(<{}[<>[]}>{[]{[(<()>
+(((({<>}<{<{<>}{[]{[]{}
+[[<[([]))<([[{}[[()]]]
+[{[{({}]{}}([{[{{{}}([]
+{<[[]]>}<{[{[{[]{()[[[]
+[<(<(<(<{}))><([]([]()
+<{([([[(<>()){}]>(<<{{
+<{([{{}}[<[[[<>{}]]]>[]]";
+
+    #[test]
+    fn part_1_example() {
+        let day = Day10::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part_1().unwrap(), 26397);
+    }
+
+    #[test]
+    fn part_2_example() {
+        let day = Day10::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part_2().unwrap(), 288957);
+    }
+}
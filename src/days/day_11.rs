@@ -0,0 +1,148 @@
+use crate::grid::Point;
+use crate::AocError;
+use crate::Grid;
+use crate::Solution;
+
+// Not every grid ever flashes all at once (`29` never
+// does), so part 2 gives up after this many steps.
+const MAX_STEPS: i64 = 10_000;
+
+#[derive(Clone)]
+pub struct Day11 {
+    octopuses: Grid<i64>,
+}
+
+impl Day11 {
+    fn flashes(&self, point: Point) -> bool {
+        // Check if equal to 10 so that each
+        // octopus can only flash once per step.
+        return self.octopuses[point] == 10;
+    }
+
+    fn step(&mut self) -> i64 {
+        let mut flashes = vec![];
+        for octopus in self.octopuses.points() {
+            self.octopuses[octopus] += 1;
+
+            if self.flashes(octopus) {
+                flashes.push(octopus);
+            }
+        }
+    
+        let mut reset = vec![];
+        while !flashes.is_empty() {
+            let octopus = match flashes.pop() {
+                Some(value) => value,
+                None => panic!("Tried to pop, but no value found"),
+            };
+    
+            let neighbors: Vec<Point> = self.octopuses.neighbors_8(octopus).collect();
+            for neighbor in neighbors {
+                self.octopuses[neighbor] += 1;
+    
+                if self.flashes(neighbor) {
+                    flashes.push(neighbor);
+                }
+            }
+    
+            reset.push(octopus);
+        }
+    
+        let mut flashes = 0;
+        for octopus in &reset {
+            flashes += 1;
+    
+            self.octopuses[*octopus] = 0;
+        }
+    
+        return flashes;
+    }
+}
+
+impl Solution for Day11 {
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Day11, AocError> {
+        return Ok(Day11 {
+            octopuses: Grid::parse(input, |ch| ch.to_digit(10).map(i64::from))?,
+        });
+    }
+
+    fn part_1(&self) -> Result<i64, AocError> {
+        return solve_part_1(self);
+    }
+
+    fn part_2(&self) -> Result<i64, AocError> {
+        return solve_part_2(self);
+    }
+}
+
+fn solve_part_1(day: &Day11) -> Result<i64, AocError> {
+    let mut clone = day.clone();
+
+    let mut flashes = 0;
+    for _ in 0..100 {
+        flashes += clone.step();
+    }
+
+    return Ok(flashes);
+}
+
+fn solve_part_2(day: &Day11) -> Result<i64, AocError> {
+    let mut clone = day.clone();
+    let octopuses = (day.octopuses.width * day.octopuses.height) as i64;
+
+    let mut steps = 1;
+    while clone.step() != octopuses {
+        if steps == MAX_STEPS {
+            return Err(AocError::NoSolution);
+        }
+
+        steps += 1;
+    }
+
+    return Ok(steps);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+5483143223
+2745854711
+5264556173
+6141336146
+6357385478
+4167524645
+2176841721
+6882881134
+4846848554
+5283751526";
+
+    #[test]
+    fn part_1_example() {
+        let day = Day11::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part_1().unwrap(), 1656);
+    }
+
+    #[test]
+    fn part_2_example() {
+        let day = Day11::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part_2().unwrap(), 195);
+    }
+
+    #[test]
+    fn part_2_other_sizes() {
+        let examples = [("5", 5), ("98\n89", 1), ("11111\n19991\n19191\n19991\n11111", 6)];
+
+        for (input, expected) in examples {
+            let day = Day11::parse(input).unwrap();
+            assert_eq!(day.part_2().unwrap(), expected, "{}", input);
+        }
+
+        let day = Day11::parse("29").unwrap();
+        assert!(matches!(day.part_2(), Err(AocError::NoSolution)));
+    }
+}
@@ -0,0 +1,205 @@
+use crate as aoc;
+use crate::AocError;
+use crate::Solution;
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+type CaveSet = HashSet<String>;
+type CaveMap = HashMap<String, CaveSet>;
+
+pub struct Day12 {
+    caves: CaveMap,
+}
+
+trait IsLowercase {
+    fn is_lowercase(&self) -> bool;
+}
+
+impl IsLowercase for str {
+    fn is_lowercase(&self) -> bool {
+        return self.chars().all(|c| c.is_lowercase());
+    }
+}
+
+impl Solution for Day12 {
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Day12, AocError> {
+        let mut day = Day12 {
+            caves: CaveMap::new(),
+        };
+
+        for (number, line) in aoc::numbered_lines(input) {
+            let (src, dest) = parse_line(number, line)?;
+            connect_path(&mut day.caves, &src, &dest);
+            connect_path(&mut day.caves, &dest, &src);
+        }
+
+        return Ok(day);
+    }
+
+    fn part_1(&self) -> Result<i64, AocError> {
+        return solve_part_1(self);
+    }
+
+    fn part_2(&self) -> Result<i64, AocError> {
+        return solve_part_2(self);
+    }
+}
+
+fn parse_line(number: usize, line: &str) -> Result<(String, String), AocError> {
+    let mut parts = line.split("-");
+
+    let src = match parts.next() {
+        Some(value) => value.to_string(),
+        None => return Err(aoc::missing_token(number, line, "No source cave found")),
+    };
+
+    let dest = match parts.next() {
+        Some(value) => value.to_string(),
+        None => return Err(aoc::missing_token(number, line, "No destination cave found")),
+    };
+
+    return Ok((src, dest));
+}
+
+fn connect_path(caves: &mut CaveMap, src: &str, dest: &str) {
+    if !caves.contains_key(src) {
+        caves.insert(src.to_string(), CaveSet::new());
+    }
+
+    if let Some(value) = caves.get_mut(src) {
+        value.insert(dest.to_string());
+    }
+}
+
+fn navigate(caves: &CaveMap, part_1: bool) -> i64 {
+    let mut visited = vec![];
+    return traverse("start", &mut visited, caves, part_1);
+}
+
+fn traverse<'a>(at: &'a str, visited: &mut Vec<&'a str>, caves: &'a CaveMap, was_duplicate: bool) -> i64 {
+    if at == "end" {
+        return 1;
+    }
+
+    let mut is_duplicate = was_duplicate;
+    if at.is_lowercase() {
+        if visited.contains(&at) {
+            is_duplicate = true;
+        }
+    }
+
+    let mut paths = 0;
+    if let Some(neighbors) = caves.get(at) {
+        for next in neighbors {
+            if next == "start" {
+                continue;
+            }
+
+            if is_duplicate {
+                if next.is_lowercase() {
+                    if visited.contains(&next.as_str()) {
+                        continue;
+                    }
+                }
+            }
+    
+            if at.is_lowercase() {
+                visited.push(at);
+            }
+    
+            paths += traverse(next, visited, caves, is_duplicate);
+    
+            if at.is_lowercase() {
+                visited.pop();
+            }
+        }
+    }
+
+    return paths;
+}
+
+fn solve_part_1(day: &Day12) -> Result<i64, AocError> {
+    return Ok(navigate(&day.caves, true));
+}
+
+fn solve_part_2(day: &Day12) -> Result<i64, AocError> {
+    return Ok(navigate(&day.caves, false));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_1: &str = "\
+start-A
+start-b
+A-c
+A-b
+b-d
+A-end
+b-end";
+
+    const EXAMPLE_2: &str = "\
+dc-end
+HN-start
+start-kj
+dc-start
+dc-HN
+LN-dc
+HN-end
+kj-sa
+kj-HN
+kj-dc";
+
+    const EXAMPLE_3: &str = "\
+fs-end
+he-DX
+fs-he
+start-DX
+pj-DX
+end-zg
+zg-sl
+zg-pj
+pj-he
+RW-he
+fs-DX
+pj-RW
+zg-RW
+start-pj
+he-WI
+zg-he
+pj-fs
+start-RW";
+
+    #[test]
+    fn part_1_examples() {
+        let examples = [
+            (EXAMPLE_1, 10),
+            (EXAMPLE_2, 19),
+            (EXAMPLE_3, 226),
+        ];
+
+        for (input, expected) in examples {
+            let day = Day12::parse(input).unwrap();
+            assert_eq!(day.part_1().unwrap(), expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn part_2_examples() {
+        let examples = [
+            (EXAMPLE_1, 36),
+            (EXAMPLE_2, 103),
+            (EXAMPLE_3, 3509),
+        ];
+
+        for (input, expected) in examples {
+            let day = Day12::parse(input).unwrap();
+            assert_eq!(day.part_2().unwrap(), expected, "{}", input);
+        }
+    }
+}
@@ -0,0 +1,250 @@
+use crate as aoc;
+use crate::AocError;
+use crate::Solution;
+
+use std::collections::HashSet;
+
+#[derive(Clone)]
+enum Axis {
+    X, Y
+}
+
+#[derive(Clone)]
+struct FoldAlong {
+    axis: Axis,
+    value: i64,
+}
+
+type Point = (i64, i64);
+
+#[derive(Clone)]
+pub struct Day13 {
+    points: HashSet<Point>,
+    fold_alongs: Vec<FoldAlong>,
+}
+
+impl Solution for Day13 {
+    type Part1 = i64;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Day13, AocError> {
+        let mut day = Day13 {
+            points: HashSet::new(),
+            fold_alongs: vec![],
+        };
+
+        for (number, line) in aoc::numbered_lines(input) {
+            if let Some(point) = parse_point(number, line)? {
+                day.points.insert(point);
+            }
+
+            if let Some(fold_along) = parse_fold_along(number, line)? {
+                day.fold_alongs.push(fold_along);
+            }
+        }
+
+        return Ok(day);
+    }
+
+    fn part_1(&self) -> Result<i64, AocError> {
+        return solve_part_1(self);
+    }
+
+    fn part_2(&self) -> Result<String, AocError> {
+        return solve_part_2(self);
+    }
+}
+
+fn parse_point(number: usize, line: &str) -> Result<Option<Point>, AocError> {
+    if line.contains(",") {
+        let mut parts = line.split(",");
+
+        let x = match parts.next() {
+            Some(value) => aoc::parse_token(number, line, value)?,
+            None => return Err(aoc::missing_token(number, line, "No x coordinate found")),
+        };
+
+        let y = match parts.next() {
+            Some(value) => aoc::parse_token(number, line, value)?,
+            None => return Err(aoc::missing_token(number, line, "No y coordinate found")),
+        };
+
+        return Ok(Some((x, y)));
+    }
+
+    return Ok(None);
+}
+
+fn parse_axis(number: usize, line: &str, input: &str) -> Result<Axis, AocError> {
+    return match input {
+        "x" => Ok(Axis::X),
+        "y" => Ok(Axis::Y),
+        unknown => {
+            let message = format!("Unknown axis: {}", unknown);
+            Err(AocError::parse(number, aoc::column_of(line, input), &message))
+        },
+    };
+}
+
+fn parse_fold_along(number: usize, line: &str) -> Result<Option<FoldAlong>, AocError> {
+    if line.starts_with("fold along ") {
+        if let Some(equ) = line.strip_prefix("fold along ") {
+            let mut parts = equ.split("=");
+
+            let fold_along = FoldAlong {
+                axis: match parts.next() {
+                    Some(value) => parse_axis(number, line, value)?,
+                    None => return Err(aoc::missing_token(number, line, "No fold along axis found")),
+                },
+                value: match parts.next() {
+                    Some(value) => aoc::parse_token(number, line, value)?,
+                    None => return Err(aoc::missing_token(number, line, "No fold along value found")),
+                },
+            };
+
+            return Ok(Some(fold_along));
+        }
+    }
+
+    return Ok(None);
+}
+
+fn fold_x(points: &mut HashSet<Point>, value: i64) {
+    let mut moved_by_fold = vec![];
+    for point in points.iter() {
+        if point.0 > value {
+            moved_by_fold.push(*point);
+        }
+    }
+
+    points.retain(|p| p.0 < value);
+    
+    for point in &moved_by_fold {
+        let delta = point.0 - value;
+        points.insert((value - delta, point.1));
+    }
+}
+
+fn fold_y(points: &mut HashSet<Point>, value: i64) {
+    let mut moved_by_fold = vec![];
+    for point in points.iter() {
+        if point.1 > value {
+            moved_by_fold.push(*point);
+        }
+    }
+
+    points.retain(|p| p.1 < value);
+    
+    for point in &moved_by_fold {
+        let delta = point.1 - value;
+        points.insert((point.0, value - delta));
+    }
+}
+
+fn solve_part_1(day: &Day13) -> Result<i64, AocError> {
+    let mut clone = day.clone();
+    if !clone.fold_alongs.is_empty() {
+        let fold_along = &clone.fold_alongs[0];
+
+        match fold_along.axis {
+            Axis::X => fold_x(&mut clone.points, fold_along.value),
+            Axis::Y => fold_y(&mut clone.points, fold_along.value),
+        };
+    }
+
+    return Ok(clone.points.len() as i64);
+}
+
+fn calc_width(points: &HashSet<Point>) -> Result<usize, AocError> {
+    return match points.iter().max_by_key(|p| p.0) {
+        Some(value) => Ok((value.0 + 1) as usize),
+        None => Err(AocError::NoSolution),
+    };
+}
+
+fn calc_height(points: &HashSet<Point>) -> Result<usize, AocError> {
+    return match points.iter().max_by_key(|p| p.1) {
+        Some(value) => Ok((value.1 + 1) as usize),
+        None => Err(AocError::NoSolution),
+    };
+}
+
+fn capture(points: &HashSet<Point>) -> Result<String, AocError> {
+    let width = calc_width(points)?;
+    let height = calc_height(points)?;
+    let mut sensor = vec![vec![' '; width]; height];
+
+    for point in points {
+        sensor[point.1 as usize][point.0 as usize] = '█';
+    }
+
+    let mut output = String::new();
+    for y in 0..height {
+        output.push('\n');
+        for x in 0..width {
+            output.push(sensor[y][x]);
+        }
+    }
+
+    return Ok(output);
+}
+
+fn solve_part_2(day: &Day13) -> Result<String, AocError> {
+    let mut clone = day.clone();
+    for fold_along in &clone.fold_alongs {
+        match fold_along.axis {
+            Axis::X => fold_x(&mut clone.points, fold_along.value),
+            Axis::Y => fold_y(&mut clone.points, fold_along.value),
+        };
+    }
+
+    return capture(&clone.points);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+6,10
+0,14
+9,10
+0,3
+10,4
+4,11
+6,0
+6,12
+4,1
+0,13
+10,12
+3,4
+3,0
+8,4
+1,10
+2,14
+8,10
+9,0
+
+fold along y=7
+fold along x=5";
+
+    // Each captured row starts with a newline.
+    const SQUARE: &str = "
+█████
+█   █
+█   █
+█   █
+█████";
+
+    #[test]
+    fn part_1_example() {
+        let day = Day13::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part_1().unwrap(), 17);
+    }
+
+    #[test]
+    fn part_2_example() {
+        let day = Day13::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part_2().unwrap(), SQUARE);
+    }
+}
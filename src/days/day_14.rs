@@ -0,0 +1,265 @@
+use crate as aoc;
+use crate::AocError;
+use crate::Solution;
+
+use std::collections::HashMap;
+
+#[derive(Clone)]
+pub struct Day14 {
+    polymer_template: String,
+    pair_insertion_rules: HashMap<String, String>,
+}
+
+impl Solution for Day14 {
+    type Part1 = u128;
+    type Part2 = u128;
+
+    fn parse(input: &str) -> Result<Day14, AocError> {
+        let mut day = Day14 {
+            polymer_template: String::new(),
+            pair_insertion_rules: HashMap::new(),
+        };
+
+        // The solutions slice the polymer by byte,
+        // so only ASCII letters are accepted.
+        let is_element = |ch: char| ch.is_ascii_alphabetic();
+
+        for (number, line) in aoc::numbered_lines(input) {
+            if number == 1 {
+                if let Some(column) = line.find(|ch| !is_element(ch)) {
+                    let message = format!("Unknown element found: `{}`", &line[column..]);
+                    return Err(AocError::parse(number, column + 1, &message));
+                }
+
+                day.polymer_template = line.to_string();
+            } else if line.contains(" -> ") {
+                let mut parts = line.split(" -> ");
+
+                let key = match parts.next() {
+                    Some(value) if value.len() == 2 && value.chars().all(is_element) => value.to_string(),
+                    _ => return Err(AocError::parse(number, 1, "No pair insertion key found")),
+                };
+
+                let value = match parts.next() {
+                    Some(value) if value.len() == 1 && value.chars().all(is_element) => value.to_string(),
+                    _ => return Err(AocError::parse(number, 7, "No pair insertion value found")),
+                };
+
+                day.pair_insertion_rules.insert(key, value);
+            } else if !line.is_empty() {
+                return Err(AocError::parse(number, 1, "Expected a pair insertion rule `AB -> C`"));
+            }
+        }
+
+        if day.polymer_template.is_empty() {
+            return Err(AocError::parse(1, 1, "No polymer template found"));
+        }
+
+        return Ok(day);
+    }
+
+    fn part_1(&self) -> Result<u128, AocError> {
+        return solve_part_1(self);
+    }
+
+    fn part_2(&self) -> Result<u128, AocError> {
+        return solve_part_2(self);
+    }
+}
+
+trait MinMax<T> {
+    fn min(&self) -> T;
+    fn max(&self) -> T;
+    fn max_minus_min(&self) -> T;
+}
+
+impl MinMax<u128> for HashMap<String, u128> {
+    fn min(&self) -> u128 {
+        let values = self.values();
+        if let Some(minimum) = values.min() {
+            return *minimum;
+        }
+
+        return 0;
+    }
+
+    fn max(&self) -> u128 {
+        let values = self.values();
+        if let Some(maximum) = values.max() {
+            return *maximum;
+        }
+
+        return 0;
+    }
+
+    fn max_minus_min(&self) -> u128 {
+        return self.max() - self.min();
+    }
+}
+
+fn apply_step(day: &mut Day14) {
+    let mut insertions = vec![];
+    for i in 0..day.polymer_template.len() - 1 {
+        let slice = &day.polymer_template[i..];
+        for pair_insertion_rule in &day.pair_insertion_rules {
+            if slice.starts_with(pair_insertion_rule.0) {
+                insertions.push((i + 1, pair_insertion_rule.1));
+            }
+        }
+    }
+
+    insertions.sort_by_key(|x| -(x.0 as i64));
+
+    for insertion in insertions {
+        day.polymer_template.insert_str(insertion.0, insertion.1);
+    }
+}
+
+fn solve_part_1(day: &Day14) -> Result<u128, AocError> {
+    let mut clone = day.clone();
+
+    for _ in 0..10 {
+        apply_step(&mut clone);
+    }
+
+    let mut quantities = HashMap::new();
+    for ch in clone.polymer_template.chars() {
+        let key = ch.to_string();
+        quantities.increment_by(&key, 1);
+    }
+
+    return Ok(quantities.max_minus_min());
+}
+
+trait ModifyBy<K, V> {
+    fn decrement_by(&mut self, key: &K, amount: V);
+    fn increment_by(&mut self, key: &K, amount: V);
+}
+
+// The counts grow exponentially with the number of
+// steps, so they are unsigned to double the headroom.
+impl ModifyBy<String, u128> for HashMap<String, u128> {
+    fn decrement_by(&mut self, key: &String, amount: u128) {
+        let entry = self.entry(key.to_string());
+        let value = entry.or_insert(0);
+        *value -= amount;
+    }
+
+    fn increment_by(&mut self, key: &String, amount: u128) {
+        let entry = self.entry(key.to_string());
+        let value = entry.or_insert(0);
+        *value += amount;
+    }
+}
+
+fn make_key(s1: &str, s2: &str) -> String {
+    return s1.to_owned() + s2;
+}
+
+fn apply_step_fast(histogram: &mut HashMap<String, u128>, day: &Day14) {
+    let mut deletions = HashMap::new();
+    let mut insertions = HashMap::new();
+
+    for pair_insertion_rule in &day.pair_insertion_rules {
+        let key = pair_insertion_rule.0.to_string();
+
+        if histogram.contains_key(&key) {
+            let prefix = key[0..1].to_string();
+            let suffix = key[1..2].to_string();
+
+            let new_key_1 = make_key(&prefix, pair_insertion_rule.1);
+            let new_key_2 = make_key(pair_insertion_rule.1, &suffix);
+
+            let current_value = *histogram.get(&key).unwrap();
+
+            deletions.increment_by(&key, current_value);
+            insertions.increment_by(&new_key_1, current_value);
+            insertions.increment_by(&new_key_2, current_value);
+        }
+    }
+
+    for deletion in &deletions {
+        histogram.decrement_by(deletion.0, *deletion.1);
+    }
+
+    for insertion in &insertions {
+        histogram.increment_by(insertion.0, *insertion.1);
+    }
+}
+
+fn solve_part_2(day: &Day14) -> Result<u128, AocError> {
+    let mut histogram = HashMap::new();
+
+    // Initialize the histogram with all pairs
+    // of characters from the given polymer_template.
+    for i in 0..day.polymer_template.len() - 1 {
+        let key = &day.polymer_template[i..i+2];
+        histogram.increment_by(&key.to_string(), 1);
+    }
+
+    for _ in 0..40 {
+        apply_step_fast(&mut histogram, day);
+    }
+
+    let mut quantities = HashMap::from([
+        (day.polymer_template[0..1].to_string(), 1)
+    ]);
+
+    for result in &histogram {
+        let key = result.0[1..2].to_string();
+        quantities.increment_by(&key, *result.1);
+    }
+
+    return Ok(quantities.max_minus_min());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+NNCB
+
+CH -> B
+HH -> N
+CB -> H
+NH -> C
+HB -> C
+HC -> B
+HN -> C
+NN -> C
+BH -> H
+NC -> B
+NB -> B
+BN -> B
+BB -> N
+BC -> B
+CC -> N
+CN -> C";
+
+    #[test]
+    fn part_1_example() {
+        let day = Day14::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part_1().unwrap(), 1588);
+    }
+
+    #[test]
+    fn part_2_example() {
+        let day = Day14::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part_2().unwrap(), 2188189693529);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let examples = [
+            ("", "line 1, column 1: No polymer template found"),
+            ("\nCH -> B", "line 1, column 1: No polymer template found"),
+            ("NNCB\n\nCH -> ", "line 3, column 7: No pair insertion value found"),
+            ("NNCB\n\nCH => B", "line 3, column 1: Expected a pair insertion rule `AB -> C`"),
+        ];
+
+        for (input, expected) in examples {
+            assert_eq!(Day14::parse(input).err().unwrap().to_string(), expected, "{}", input);
+        }
+    }
+}
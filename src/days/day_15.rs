@@ -0,0 +1,303 @@
+use crate::grid::Point;
+use crate::search;
+use crate::search::Path;
+use crate::search::SearchSpace;
+use crate::AocError;
+use crate::Grid;
+use crate::Solution;
+use crate::Visualization;
+
+use colored::*;
+use std::collections::HashSet;
+
+// The full map is 5 times larger than the scanned
+// map in both directions (the first part uses 1).
+const TILES: [usize; 2] = [1, 5];
+
+pub struct Day15 {
+    risk_levels: Grid<i64>,
+}
+
+impl Solution for Day15 {
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Day15, AocError> {
+        return Ok(Day15 {
+            // A risk level of 0 would make the heuristic
+            // of `Cavern` overestimate, so it is rejected.
+            risk_levels: Grid::parse(input, |ch| ch.to_digit(10).filter(|digit| (1..=9).contains(digit)).map(i64::from))?,
+        });
+    }
+
+    fn part_1(&self) -> Result<i64, AocError> {
+        return solve_part_1(self);
+    }
+
+    fn part_2(&self) -> Result<i64, AocError> {
+        return solve_part_2(self);
+    }
+
+    fn visualize(&self, part: i64, format: Visualization) -> Result<Option<String>, AocError> {
+        let tiles = match part {
+            1 | 2 => TILES[(part - 1) as usize],
+            _ => return Err(AocError::NoSolution),
+        };
+
+        let cavern = Cavern::new(self, tiles);
+
+        let path = lowest_risk_path(&cavern, true)?;
+        return Ok(Some(match format {
+            Visualization::Terminal => render(&cavern, &path),
+            Visualization::Ppm => to_ppm(&cavern, &path),
+        }));
+    }
+}
+
+impl Day15 {
+    // The lowest total risk from the top left to the bottom right of
+    // the map repeated `tiles` times in both directions, 0 has no path.
+    pub fn lowest_risk(&self, tiles: usize) -> Result<i64, AocError> {
+        let cavern = Cavern::new(self, tiles);
+        return lowest_risk_path(&cavern, true).map(|path| path.cost);
+    }
+}
+
+// The scanned map repeated `tiles` times in both directions. The risk
+// levels of the copies are computed when needed instead of stored.
+struct Cavern<'a> {
+    risk_levels: &'a Grid<i64>,
+    width: usize,
+    height: usize,
+}
+
+impl Cavern<'_> {
+    fn new(day: &Day15, tiles: usize) -> Cavern<'_> {
+        return Cavern {
+            risk_levels: &day.risk_levels,
+            width: tiles * day.risk_levels.width,
+            height: tiles * day.risk_levels.height,
+        };
+    }
+
+    // Each tile is one higher than the
+    // tile above or to its left.
+    fn risk_level(&self, position: Point) -> i64 {
+        let (width, height) = (self.risk_levels.width, self.risk_levels.height);
+
+        let tile = (position.0 / width + position.1 / height) as i64;
+        return wrap(self.risk_levels[(position.0 % width, position.1 % height)] + tile);
+    }
+
+    fn exit(&self) -> Point {
+        return (self.width - 1, self.height - 1);
+    }
+}
+
+// Entering a position costs its risk level, the
+// risk level of the starting position is never paid.
+impl SearchSpace for Cavern<'_> {
+    type State = Point;
+
+    fn neighbors(&self, position: &Point) -> Vec<(Point, i64)> {
+        let (x, y) = *position;
+
+        let mut neighbors = vec![];
+        if x > 0 {
+            neighbors.push((x - 1, y));
+        }
+
+        if x + 1 < self.width {
+            neighbors.push((x + 1, y));
+        }
+
+        if y > 0 {
+            neighbors.push((x, y - 1));
+        }
+
+        if y + 1 < self.height {
+            neighbors.push((x, y + 1));
+        }
+
+        return neighbors.into_iter().map(|neighbor| (neighbor, self.risk_level(neighbor))).collect();
+    }
+
+    fn is_goal(&self, position: &Point) -> bool {
+        return *position == self.exit();
+    }
+
+    // Every risk level is at least 1, so the Manhattan
+    // distance never overestimates the remaining risk.
+    fn heuristic(&self, position: &Point) -> i64 {
+        let exit = self.exit();
+        return ((exit.0 - position.0) + (exit.1 - position.1)) as i64;
+    }
+}
+
+fn lowest_risk_path(cavern: &Cavern, use_heuristic: bool) -> Result<Path<Point>, AocError> {
+    if cavern.width == 0 || cavern.height == 0 {
+        return Err(AocError::NoSolution);
+    }
+
+    let path = match use_heuristic {
+        true => search::a_star(cavern, (0, 0)),
+        false => search::dijkstra(cavern, (0, 0)),
+    };
+
+    return match path {
+        Some(value) => Ok(value),
+        None => Err(AocError::NoSolution),
+    };
+}
+
+// The path is drawn in bold red on top of the dimmed
+// risk levels, one character per position.
+fn render(cavern: &Cavern, path: &Path<Point>) -> String {
+    let on_path: HashSet<&Point> = path.states.iter().collect();
+
+    let mut rendering = String::new();
+    for y in 0..cavern.height {
+        for x in 0..cavern.width {
+            let risk_level = cavern.risk_level((x, y)).to_string();
+            if on_path.contains(&(x, y)) {
+                rendering.push_str(&risk_level.red().bold().to_string());
+            } else {
+                rendering.push_str(&risk_level.dimmed().to_string());
+            }
+        }
+
+        rendering.push('\n');
+    }
+
+    return rendering;
+}
+
+// A plain text (P3) image: higher risk levels are brighter
+// shades of gray and the path is pure red, one pixel each.
+fn to_ppm(cavern: &Cavern, path: &Path<Point>) -> String {
+    let on_path: HashSet<&Point> = path.states.iter().collect();
+
+    let mut ppm = format!("P3\n{} {}\n255\n", cavern.width, cavern.height);
+    for y in 0..cavern.height {
+        let mut pixels = vec![];
+        for x in 0..cavern.width {
+            if on_path.contains(&(x, y)) {
+                pixels.push(String::from("255 0 0"));
+            } else {
+                let shade = cavern.risk_level((x, y)) * 28;
+                pixels.push(format!("{} {} {}", shade, shade, shade));
+            }
+        }
+
+        ppm.push_str(&pixels.join(" "));
+        ppm.push('\n');
+    }
+
+    return ppm;
+}
+
+// Risk levels above 9 wrap back around to 1.
+fn wrap(value: i64) -> i64 {
+    return (value - 1) % 9 + 1;
+}
+
+fn solve_part_1(day: &Day15) -> Result<i64, AocError> {
+    return day.lowest_risk(TILES[0]);
+}
+
+fn solve_part_2(day: &Day15) -> Result<i64, AocError> {
+    return day.lowest_risk(TILES[1]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581";
+
+    #[test]
+    fn part_1_example() {
+        let day = Day15::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part_1().unwrap(), 40);
+    }
+
+    #[test]
+    fn part_2_example() {
+        let day = Day15::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part_2().unwrap(), 315);
+    }
+
+    #[test]
+    fn lowest_risk_path_example() {
+        let day = Day15::parse(EXAMPLE).unwrap();
+        let path = lowest_risk_path(&Cavern::new(&day, 1), true).unwrap();
+
+        assert_eq!(path.states[0], (0, 0));
+        assert_eq!(path.states[path.states.len() - 1], (9, 9));
+
+        let risk: i64 = path.states[1..].iter().map(|point| day.risk_levels[*point]).sum();
+        assert_eq!((risk, path.cost), (40, 40));
+    }
+
+    #[test]
+    fn dijkstra_and_a_star_agree() {
+        let day = Day15::parse(EXAMPLE).unwrap();
+        for tiles in 1..=7 {
+            let cavern = Cavern::new(&day, tiles);
+            let dijkstra = lowest_risk_path(&cavern, false).unwrap();
+            let a_star = lowest_risk_path(&cavern, true).unwrap();
+            assert_eq!(dijkstra.cost, a_star.cost, "{} tiles", tiles);
+        }
+    }
+
+    #[test]
+    fn lowest_risk_per_tiles() {
+        let day = Day15::parse(EXAMPLE).unwrap();
+        assert_eq!(day.lowest_risk(1).unwrap(), 40);
+        assert_eq!(day.lowest_risk(5).unwrap(), 315);
+        assert_eq!(day.lowest_risk(3).unwrap(), lowest_risk_path(&Cavern::new(&day, 3), false).unwrap().cost);
+        assert!(matches!(day.lowest_risk(0), Err(AocError::NoSolution)));
+    }
+
+    #[test]
+    fn risk_levels_from_1_to_9() {
+        let error = Day15::parse("116\n130\n211").err().unwrap();
+        assert_eq!(error.to_string(), "line 2, column 3: Unknown symbol found: `0`");
+
+        let day = Day15::parse("116\n131\n211").unwrap();
+        assert!(matches!(day.visualize(3, Visualization::Terminal), Err(AocError::NoSolution)));
+    }
+
+    #[test]
+    fn tiled_risk_levels() {
+        let day = Day15::parse(EXAMPLE).unwrap();
+        let cavern = Cavern::new(&day, 9);
+
+        // The bottom right 1 of the example goes up by 8 and 16,
+        // which wraps around past 9 once for the larger one.
+        assert_eq!(cavern.risk_level((9, 9)), 1);
+        assert_eq!(cavern.risk_level((49, 49)), 9);
+        assert_eq!(cavern.risk_level((89, 89)), 8);
+    }
+
+    #[test]
+    fn ppm_of_tiled_map() {
+        let day = Day15::parse(EXAMPLE).unwrap();
+        let ppm = day.visualize(2, Visualization::Ppm).unwrap().unwrap();
+
+        let lines: Vec<&str> = ppm.lines().collect();
+        assert_eq!(lines[..3], ["P3", "50 50", "255"]);
+        assert_eq!(lines.len(), 3 + 50);
+        assert!(lines[3].starts_with("255 0 0 "));
+    }
+}
@@ -0,0 +1,352 @@
+use crate as aoc;
+use crate::AocError;
+use crate::Solution;
+
+use std::collections::HashMap;
+
+pub struct Day16 {
+    packet: Packet,
+}
+
+const PACKET_SUM: i64 = 0;
+const PACKET_PRODUCT: i64 = 1;
+const PACKET_MINIMUM: i64 = 2;
+const PACKET_MAXIMUM: i64 = 3;
+const PACKET_LITERAL: i64 = 4;
+const PACKET_GREATER_THAN: i64 = 5;
+const PACKET_LESS_THAN: i64 = 6;
+const PACKET_EQUAL_TO: i64 = 7;
+
+struct Packet {
+    version: i64,
+    type_id: i64,
+    literal: Option<i64>,
+    sub_packets: Vec<Packet>,
+}
+
+impl Packet {
+    fn new() -> Packet {
+        return Packet {
+            version: 0,
+            type_id: 0,
+            literal: None,
+            sub_packets: Vec::new(),
+        };
+    }
+
+    fn checksum(&self) -> i64 {
+        let mut total = self.version;
+        for sub_packet in &self.sub_packets {
+            total += sub_packet.checksum();
+        }
+
+        return total;
+    }
+
+    fn sum(packets: &[Packet]) -> Result<i64, AocError> {
+        let mut result = 0;
+        for packet in packets {
+            result += packet.evaluate()?;
+        }
+
+        return Ok(result);
+    }
+
+    fn product(packets: &[Packet]) -> Result<i64, AocError> {
+        let mut result = 1;
+        for packet in packets {
+            result *= packet.evaluate()?;
+        }
+
+        return Ok(result);
+    }
+
+    fn minimum(packets: &[Packet]) -> Result<i64, AocError> {
+        let mut result = None;
+        for packet in packets {
+            let value = packet.evaluate()?;
+            if result.is_none_or(|minimum| value < minimum) { result = Some(value); }
+        }
+
+        return result.ok_or(AocError::NoSolution);
+    }
+
+    fn maximum(packets: &[Packet]) -> Result<i64, AocError> {
+        let mut result = None;
+        for packet in packets {
+            let value = packet.evaluate()?;
+            if result.is_none_or(|maximum| value > maximum) { result = Some(value); }
+        }
+
+        return result.ok_or(AocError::NoSolution);
+    }
+
+    // Comparisons always have exactly two sub-packets.
+    fn compare(packets: &[Packet], holds: fn(i64, i64) -> bool) -> Result<i64, AocError> {
+        return match packets {
+            [first, second] => Ok(holds(first.evaluate()?, second.evaluate()?) as i64),
+            _ => Err(AocError::NoSolution),
+        };
+    }
+
+    fn evaluate(&self) -> Result<i64, AocError> {
+        return match self.type_id {
+            PACKET_LITERAL      => self.literal.ok_or(AocError::NoSolution),
+            PACKET_SUM          => Packet::sum(&self.sub_packets),
+            PACKET_PRODUCT      => Packet::product(&self.sub_packets),
+            PACKET_MINIMUM      => Packet::minimum(&self.sub_packets),
+            PACKET_MAXIMUM      => Packet::maximum(&self.sub_packets),
+            PACKET_EQUAL_TO     => Packet::compare(&self.sub_packets, |first, second| first == second),
+            PACKET_LESS_THAN    => Packet::compare(&self.sub_packets, |first, second| first < second),
+            PACKET_GREATER_THAN => Packet::compare(&self.sub_packets, |first, second| first > second),
+            _ => Err(AocError::NoSolution),
+        };
+    }
+}
+
+// The transmission is a single line of hex digits, so a bit
+// offset is reported as the column of the digit it falls in.
+fn bit_error(offset: usize, message: &str) -> AocError {
+    return AocError::parse(1, offset / 4 + 1, &format!("bit {}: {}", offset, message));
+}
+
+fn read_bits(binary: &str, offset: usize, length: usize) -> Result<i64, AocError> {
+    let bits = match binary.get(offset..(offset + length)) {
+        Some(value) => value,
+        None => {
+            let message = format!("Expected {} bits, found {}", length, binary.len().saturating_sub(offset));
+            return Err(bit_error(offset, &message));
+        },
+    };
+
+    let mut value = 0;
+    for ch in bits.chars() {
+        value <<= 1;
+        if ch == '1' { value += 1; }
+    }
+
+    return Ok(value);
+}
+
+fn hexadecimal_to_binary(ch: char) -> Option<&'static str> {
+    let symbols = HashMap::from([
+        ('0', "0000"), ('1', "0001"), ('2', "0010"), ('3', "0011"),
+        ('4', "0100"), ('5', "0101"), ('6', "0110"), ('7', "0111"),
+        ('8', "1000"), ('9', "1001"), ('A', "1010"), ('B', "1011"),
+        ('C', "1100"), ('D', "1101"), ('E', "1110"), ('F', "1111"),
+    ]);
+
+    return symbols.get(&ch).copied();
+}
+
+fn to_binary(input: &str) -> Result<String, AocError> {
+    let mut binary = String::new();
+    for (number, line) in aoc::numbered_lines(input) {
+        for (column, ch) in line.char_indices() {
+            match hexadecimal_to_binary(ch) {
+                Some(value) => binary.push_str(value),
+                None => return Err(AocError::parse(number, column + 1, "No hex symbol found")),
+            };
+        }
+    }
+
+    return Ok(binary);
+}
+
+fn read_literal(binary: &str, offset: usize) -> Result<(usize, i64), AocError> {
+    let mut i = offset;
+    let mut value: i64 = 0;
+
+    loop {
+        let group = read_bits(binary, i, 5)?;
+        if value > (i64::MAX >> 4) {
+            return Err(bit_error(i, "Literal does not fit in 63 bits"));
+        }
+
+        i += 5;
+        value = (value << 4) | (group & 0b1111);
+        if group & 0b10000 == 0 { break; }
+    }
+
+    return Ok((i - offset, value));
+}
+
+fn read_packet(binary: &str, offset: usize) -> Result<(usize, Packet), AocError> {
+    let mut consumed = 0;
+    let mut packet = Packet::new();
+
+    packet.version = read_bits(binary, offset, 3)?;
+    packet.type_id = read_bits(binary, offset + 3, 3)?;
+
+    consumed += 6;
+    if packet.type_id == PACKET_LITERAL {
+        let literal = read_literal(binary, offset + 6)?;
+
+        consumed += literal.0;
+        packet.literal = Some(literal.1);
+    } else {
+        let length_type_id = read_bits(binary, offset + 6, 1)?;
+
+        consumed += 1;
+        if length_type_id == 0 {
+            let bits_in_sub_packet = read_bits(binary, offset + 7, 15)?;
+
+            consumed += 15;
+            let mut processed = 0;
+            while processed < (bits_in_sub_packet as usize) {
+                let sub_packet_offset = offset + consumed + processed;
+                let sub_packet = read_packet(binary, sub_packet_offset)?;
+
+                processed += sub_packet.0;
+                packet.sub_packets.push(sub_packet.1);
+            }
+
+            if processed != bits_in_sub_packet as usize {
+                let message = format!("Sub-packets took {} bits instead of {}", processed, bits_in_sub_packet);
+                return Err(bit_error(offset + 7, &message));
+            }
+
+            consumed += processed;
+        } else {
+            let num_sub_packets = read_bits(binary, offset + 7, 11)?;
+
+            consumed += 11;
+            for _ in 0..num_sub_packets {
+                let sub_packet_offset = offset + consumed;
+                let subpacket = read_packet(binary, sub_packet_offset)?;
+
+                consumed += subpacket.0;
+                packet.sub_packets.push(subpacket.1);
+            }
+        }
+
+        if [PACKET_EQUAL_TO, PACKET_LESS_THAN, PACKET_GREATER_THAN].contains(&packet.type_id) && packet.sub_packets.len() != 2 {
+            let message = format!("Expected 2 sub-packets to compare, found {}", packet.sub_packets.len());
+            return Err(bit_error(offset, &message));
+        } else if packet.sub_packets.is_empty() {
+            return Err(bit_error(offset, "Operator without sub-packets"));
+        }
+    }
+
+    return Ok((consumed, packet));
+}
+
+impl Solution for Day16 {
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Day16, AocError> {
+        let (_, packet) = read_packet(&to_binary(input)?, 0)?;
+        return Ok(Day16 {
+            packet: packet,
+        });
+    }
+
+    fn part_1(&self) -> Result<i64, AocError> {
+        return solve_part_1(self);
+    }
+
+    fn part_2(&self) -> Result<i64, AocError> {
+        return solve_part_2(self);
+    }
+}
+
+fn solve_part_1(day: &Day16) -> Result<i64, AocError> {
+    return Ok(day.packet.checksum());
+}
+
+fn solve_part_2(day: &Day16) -> Result<i64, AocError> {
+    return day.packet.evaluate();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_1_examples() {
+        let examples = [
+            ("8A004A801A8002F478", 16),
+            ("620080001611562C8802118E34", 12),
+            ("C0015000016115A2E0802F182340", 23),
+            ("A0016C880162017C3686B18A3D4780", 31),
+        ];
+
+        for (input, expected) in examples {
+            let day = Day16::parse(input).unwrap();
+            assert_eq!(day.part_1().unwrap(), expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn part_2_examples() {
+        let examples = [
+            ("C200B40A82", 3),
+            ("04005AC33890", 54),
+            ("880086C3E88112", 7),
+            ("CE00C43D881120", 9),
+            ("D8005AC2A8F0", 1),
+            ("F600BC2D8F", 0),
+            ("9C005AC2F8F0", 0),
+            ("9C0141080250320F1802104A08", 1),
+        ];
+
+        for (input, expected) in examples {
+            let day = Day16::parse(input).unwrap();
+            assert_eq!(day.part_2().unwrap(), expected, "{}", input);
+        }
+    }
+
+    fn binary(hexadecimal: &str) -> String {
+        return to_binary(hexadecimal).unwrap();
+    }
+
+    #[test]
+    fn read_packet_literal() {
+        let (consumed, packet) = read_packet(&binary("D2FE28"), 0).unwrap();
+
+        assert_eq!(consumed, 21);
+        assert_eq!(packet.version, 6);
+        assert_eq!(packet.type_id, PACKET_LITERAL);
+        assert_eq!(packet.literal, Some(2021));
+    }
+
+    #[test]
+    fn read_packet_operator_with_bit_length() {
+        let (consumed, packet) = read_packet(&binary("38006F45291200"), 0).unwrap();
+
+        assert_eq!(consumed, 49);
+        assert_eq!(packet.version, 1);
+        assert_eq!(packet.type_id, PACKET_LESS_THAN);
+
+        let literals: Vec<Option<i64>> = packet.sub_packets.iter().map(|p| p.literal).collect();
+        assert_eq!(literals, vec![Some(10), Some(20)]);
+    }
+
+    #[test]
+    fn read_packet_operator_with_packet_count() {
+        let (consumed, packet) = read_packet(&binary("EE00D40C823060"), 0).unwrap();
+
+        assert_eq!(consumed, 51);
+        assert_eq!(packet.version, 7);
+        assert_eq!(packet.type_id, PACKET_MAXIMUM);
+
+        let literals: Vec<Option<i64>> = packet.sub_packets.iter().map(|p| p.literal).collect();
+        assert_eq!(literals, vec![Some(1), Some(2), Some(3)]);
+    }
+
+    #[test]
+    fn truncated_transmissions() {
+        let examples = [
+            ("", "line 1, column 1: bit 0: Expected 3 bits, found 0"),
+            ("D2FE", "line 1, column 5: bit 16: Expected 5 bits, found 0"),
+            ("38006F4529", "line 1, column 10: bit 39: Expected 5 bits, found 1"),
+            ("780000", "line 1, column 1: bit 0: Expected 2 sub-packets to compare, found 0"),
+        ];
+
+        for (input, expected) in examples {
+            let error = Day16::parse(input).err().unwrap();
+            assert_eq!(error.to_string(), expected, "{}", input);
+        }
+    }
+}
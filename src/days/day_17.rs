@@ -0,0 +1,201 @@
+use crate as aoc;
+use crate::AocError;
+use crate::Solution;
+
+use std::ops::RangeInclusive;
+
+pub struct Day17 {
+    x_range: RangeInclusive<i64>,
+    y_range: RangeInclusive<i64>,
+}
+
+fn parse_range(number: usize, line: &str, range: &str) -> Result<RangeInclusive<i64>, AocError> {
+    let mut parts = range.split("..");
+
+    let minimum = match parts.next() {
+        Some(value) => aoc::parse_token(number, line, value)?,
+        None => return Err(aoc::missing_token(number, line, "No minimum found")),
+    };
+
+    let maximum = match parts.next() {
+        Some(value) => aoc::parse_token(number, line, value)?,
+        None => return Err(aoc::missing_token(number, line, "No maximum found")),
+    };
+
+    return Ok(minimum..=maximum);
+}
+
+impl Solution for Day17 {
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Day17, AocError> {
+        let mut day = Day17 {
+            x_range: 0..=0,
+            y_range: 0..=0,
+        };
+
+        let target_area_prefix = "target area: ";
+        for (number, line) in aoc::numbered_lines(input) {
+            if line.starts_with(target_area_prefix) {
+                if let Some(target_area) = line.strip_prefix(target_area_prefix) {
+                    let mut parts = target_area.split(", ");
+
+                    if let Some(equation) = parts.next() {
+                        if let Some(range) = equation.strip_prefix("x=") {
+                            day.x_range = parse_range(number, line, range)?;
+                        }
+                    }
+
+                    if let Some(equation) = parts.next() {
+                        if let Some(range) = equation.strip_prefix("y=") {
+                            day.y_range = parse_range(number, line, range)?;
+                        }
+                    }
+                }
+            }
+        }
+
+        return Ok(day);
+    }
+
+    fn part_1(&self) -> Result<i64, AocError> {
+        return solve_part_1(self);
+    }
+
+    fn part_2(&self) -> Result<i64, AocError> {
+        return solve_part_2(self);
+    }
+}
+
+type Point = (i64, i64);
+
+struct State {
+    position: Point,
+    velocity: Point,
+}
+
+fn sign(value: i64) -> i64 {
+    if value > 0 { return 1; }
+    if value < 0 { return -1; }
+    return 0;
+}
+
+fn step(state: &State) -> State {
+    let x_position = state.position.0 + state.velocity.0;
+    let y_position = state.position.1 + state.velocity.1;
+
+    // Drag & Gravity
+    let x_velocity = state.velocity.0 - sign(state.velocity.0);
+    let y_velocity = state.velocity.1 - 1;
+
+    return State {
+        position: (x_position, y_position),
+        velocity: (x_velocity, y_velocity),
+    };
+}
+
+fn past_target(day: &Day17, state: &State) -> bool {
+    return state.position.0 > *day.x_range.end()
+        || state.position.1 < *day.y_range.start();
+}
+
+fn inside_target(day: &Day17, state: &State) -> bool {
+    return day.x_range.contains(&state.position.0)
+        && day.y_range.contains(&state.position.1);
+}
+
+fn solve_part_1(day: &Day17) -> Result<i64, AocError> {
+    // Assume X is positive.
+    let x_size = *day.x_range.end();
+
+    // Assume Y is negative.
+    let y_size = day.y_range.start().abs();
+
+    let mut global_max_y = i64::MIN;
+    for x_velocity in 0..=x_size {
+        for y_velocity in -y_size..=y_size {
+            let mut state = State {
+                position: (0, 0),
+                velocity: (x_velocity, y_velocity),
+            };
+
+            let mut max_y = i64::MIN;
+
+            loop {
+                state = step(&state);
+
+                if past_target(day, &state) {
+                    break;
+                }
+        
+                if state.position.1 > max_y {
+                    max_y = state.position.1;
+                }
+
+                if inside_target(day, &state) {
+                    if max_y > global_max_y {
+                        global_max_y = max_y;
+                    }
+
+                    break;
+                }
+            }
+        }
+    }
+
+    return Ok(global_max_y);
+}
+
+fn solve_part_2(day: &Day17) -> Result<i64, AocError> {
+    // Assume X is positive.
+    let x_size = *day.x_range.end();
+
+    // Assume Y is negative.
+    let y_size = day.y_range.start().abs();
+
+    let mut num_hits = 0;
+    for x_velocity in 0..=x_size {
+        for y_velocity in -y_size..=y_size {
+            let mut state = State {
+                position: (0, 0),
+                velocity: (x_velocity, y_velocity),
+            };
+
+            loop {
+                state = step(&state);
+
+                if past_target(day, &state) {
+                    break;
+                }
+
+                if inside_target(day, &state) {
+                    num_hits += 1;
+                    break;
+                }
+            }
+        }
+    }
+
+    return Ok(num_hits);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+target area: x=20..30, y=-10..-5";
+
+    #[test]
+    fn part_1_example() {
+        let day = Day17::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part_1().unwrap(), 45);
+    }
+
+    #[test]
+    fn part_2_example() {
+        let day = Day17::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part_2().unwrap(), 112);
+    }
+}
@@ -0,0 +1,302 @@
+use crate as aoc;
+use crate::AocError;
+use crate::Solution;
+
+#[derive(Clone)]
+struct State {
+    value: i64,
+    level: i64,
+}
+
+type SnailfishNumber = Vec<State>;
+
+#[derive(Clone)]
+pub struct Day18 {
+    snailfish_numbers: Vec<SnailfishNumber>,
+}
+
+impl Solution for Day18 {
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Day18, AocError> {
+        let mut day = Day18 {
+            snailfish_numbers: vec![],
+        };
+
+        for (number, line) in aoc::numbered_lines(input) {
+            day.snailfish_numbers.push(parse_snailfish_number(number, line)?);
+        }
+
+        return Ok(day);
+    }
+
+    fn part_1(&self) -> Result<i64, AocError> {
+        return solve_part_1(self);
+    }
+
+    fn part_2(&self) -> Result<i64, AocError> {
+        return solve_part_2(self);
+    }
+}
+
+fn parse_snailfish_number(number: usize, line: &str) -> Result<SnailfishNumber, AocError> {
+    let mut current_level = 0;
+    let mut snailfish_number = vec![];
+
+    for (column, ch) in line.char_indices() {
+        if ch == '[' {
+            current_level += 1;
+        } else if ch == ']' {
+            current_level -= 1;
+            if current_level < 0 {
+                return Err(AocError::parse(number, column + 1, "Unbalanced `]` found"));
+            }
+        } else if let Some(digit) = ch.to_digit(10) {
+            snailfish_number.push(State {
+                value: digit as i64,
+                level: current_level,
+            });
+        } else if ch != ',' {
+            let message = format!("Unknown symbol found: `{}`", ch);
+            return Err(AocError::parse(number, column + 1, &message));
+        }
+    }
+
+    if current_level != 0 {
+        return Err(aoc::missing_token(number, line, "No closing `]` found"));
+    }
+
+    return Ok(snailfish_number);
+}
+
+fn reduce(snailfish_number: &mut SnailfishNumber) {
+    loop {
+        while explode(snailfish_number) {
+            continue;
+        }
+
+        if split(snailfish_number) {
+            continue;
+        }
+
+        break;
+    }
+}
+
+fn add(acc: &mut SnailfishNumber, rhs: &SnailfishNumber) {
+    for i in 0..acc.len() {
+        acc[i].level += 1;
+    }
+
+    for i in 0..rhs.len() {
+        acc.push(State {
+            value: rhs[i].value,
+            level: rhs[i].level + 1,
+        });
+    }
+}
+
+fn explode(snailfish_number: &mut SnailfishNumber) -> bool {
+    for i in 0..(snailfish_number.len() - 1) {
+        let lhs = snailfish_number[i].clone();
+        let rhs = snailfish_number[i + 1].clone();
+
+        if (lhs.level > 4) && (rhs.level > 4) {
+            if i > 0 {
+                snailfish_number[i - 1].value += lhs.value;
+            }
+
+            if i < (snailfish_number.len() - 2) {
+                snailfish_number[i + 2].value += rhs.value;
+            }
+
+            snailfish_number[i].value = 0;
+            snailfish_number[i].level -= 1;
+            snailfish_number.remove(i + 1);
+            return true;
+        }
+    }
+
+    return false;
+}
+
+fn split(snailfish_number: &mut SnailfishNumber) -> bool {
+    for i in 0..snailfish_number.len() {
+        if snailfish_number[i].value >= 10 {
+            let value = snailfish_number[i].value;
+
+            snailfish_number[i].value = value / 2;
+            snailfish_number[i].level += 1;
+
+            let new_state = State {
+                value: (value / 2) + (value % 2),
+                level: snailfish_number[i].level,
+            };
+
+            snailfish_number.insert(i + 1, new_state);
+            return true;
+        }
+    }
+
+    return false;
+}
+
+fn magnitude(i: &mut usize, level: i64, snailfish_number: &SnailfishNumber) -> i64 {
+    let mut result = 0;
+    if snailfish_number[*i].level == level {
+        result += 3 * snailfish_number[*i].value;
+        *i += 1;
+    } else {
+        result += 3 * magnitude(i, level + 1, snailfish_number);
+    }
+
+    if snailfish_number[*i].level == level {
+        result += 2 * snailfish_number[*i].value;
+        *i += 1;
+    } else {
+        result += 2 * magnitude(i, level + 1, snailfish_number);
+    }
+
+    return result;
+}
+
+fn solve_part_1(day: &Day18) -> Result<i64, AocError> {
+    if day.snailfish_numbers.is_empty() {
+        return Err(AocError::NoSolution);
+    }
+
+    let mut result = day.snailfish_numbers[0].clone();
+
+    for i in 1..day.snailfish_numbers.len() {
+        add(&mut result, &day.snailfish_numbers[i]);
+        reduce(&mut result);
+    }
+
+    return Ok(magnitude(&mut 0, 1, &result));
+}
+
+fn solve_part_2(day: &Day18) -> Result<i64, AocError> {
+    let mut max_magnitude = i64::MIN;
+    for i in 0..day.snailfish_numbers.len() {
+        for j in i..day.snailfish_numbers.len() {
+            let mut result_1 = day.snailfish_numbers[i].clone();
+
+            add(&mut result_1, &day.snailfish_numbers[j]);
+            reduce(&mut result_1);
+
+            let magnitude_1 = magnitude(&mut 0, 1, &result_1);
+            if magnitude_1 > max_magnitude {
+                max_magnitude = magnitude_1;
+            }
+
+            // Not commutative, swap order.
+            let mut result_2 = day.snailfish_numbers[j].clone();
+            add(&mut result_2, &day.snailfish_numbers[i]);
+            reduce(&mut result_2);
+
+            let magnitude_2 = magnitude(&mut 0, 1, &result_2);
+            if magnitude_2 > max_magnitude {
+                max_magnitude = magnitude_2;
+            }
+        }
+    }
+    
+    return Ok(max_magnitude);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
+[[[5,[2,8]],4],[5,[[9,9],0]]]
+[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
+[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
+[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]
+[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]
+[[[[5,4],[7,7]],8],[[8,3],8]]
+[[9,3],[[9,9],[6,[4,9]]]]
+[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
+[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]";
+
+    #[test]
+    fn part_1_example() {
+        let day = Day18::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part_1().unwrap(), 4140);
+    }
+
+    #[test]
+    fn part_2_example() {
+        let day = Day18::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part_2().unwrap(), 3993);
+    }
+
+    fn snailfish_number(line: &str) -> SnailfishNumber {
+        return parse_snailfish_number(1, line).unwrap();
+    }
+
+    fn flatten(snailfish_number: &SnailfishNumber) -> Vec<(i64, i64)> {
+        return snailfish_number.iter().map(|state| (state.value, state.level)).collect();
+    }
+
+    #[test]
+    fn explode_examples() {
+        let examples = [
+            ("[[[[[9,8],1],2],3],4]", "[[[[0,9],2],3],4]"),
+            ("[7,[6,[5,[4,[3,2]]]]]", "[7,[6,[5,[7,0]]]]"),
+            ("[[6,[5,[4,[3,2]]]],1]", "[[6,[5,[7,0]]],3]"),
+            ("[[3,[2,[1,[7,3]]]],[6,[5,[4,[3,2]]]]]", "[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]"),
+            ("[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]", "[[3,[2,[8,0]]],[9,[5,[7,0]]]]"),
+        ];
+
+        for (before, after) in examples {
+            let mut result = snailfish_number(before);
+            assert!(explode(&mut result), "{}", before);
+            assert_eq!(flatten(&result), flatten(&snailfish_number(after)), "{}", before);
+        }
+    }
+
+    #[test]
+    fn split_example() {
+        // [[[[0,7],4],[15,[0,13]]],[1,1]] cannot be parsed
+        // since 15 and 13 are not single digits.
+        let mut result = snailfish_number("[[[[0,7],4],[5,[0,3]]],[1,1]]");
+        result[3].value = 15;
+        result[5].value = 13;
+
+        assert!(split(&mut result));
+        assert_eq!(flatten(&result), vec![(0, 4), (7, 4), (4, 3), (7, 4), (8, 4), (0, 4), (13, 4), (1, 2), (1, 2)]);
+
+        assert!(split(&mut result));
+        assert_eq!(flatten(&result), flatten(&snailfish_number("[[[[0,7],4],[[7,8],[0,[6,7]]]],[1,1]]")));
+
+        assert!(!split(&mut snailfish_number("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]")));
+    }
+
+    #[test]
+    fn add_and_reduce_example() {
+        let mut result = snailfish_number("[[[[4,3],4],4],[7,[[8,4],9]]]");
+        add(&mut result, &snailfish_number("[1,1]"));
+        reduce(&mut result);
+
+        assert_eq!(flatten(&result), flatten(&snailfish_number("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]")));
+    }
+
+    #[test]
+    fn magnitude_examples() {
+        let examples = [
+            ("[[1,2],[[3,4],5]]", 143),
+            ("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]", 1384),
+            ("[[[[1,1],[2,2]],[3,3]],[4,4]]", 445),
+            ("[[[[3,0],[5,3]],[4,4]],[5,5]]", 791),
+            ("[[[[5,0],[7,4]],[5,5]],[6,6]]", 1137),
+            ("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]", 3488),
+        ];
+
+        for (input, expected) in examples {
+            assert_eq!(magnitude(&mut 0, 1, &snailfish_number(input)), expected, "{}", input);
+        }
+    }
+}
@@ -0,0 +1,244 @@
+use crate as aoc;
+use crate::AocError;
+use crate::Solution;
+
+use std::collections::HashSet;
+use std::collections::VecDeque;
+
+pub struct Day19 {
+    scanners: Vec<Scanner>,
+}
+
+type Point = (i64, i64, i64);
+
+struct Scanner {
+    beacons: Vec<Point>,
+}
+
+impl Scanner {
+    fn new() -> Scanner {
+        return Scanner {
+            beacons: vec![],
+        };
+    }
+
+    fn add_beacon(&mut self, number: usize, line: &str) -> Result<(), AocError> {
+        let mut parts = line.split(",");
+
+        let mut x = 0;
+        if let Some(coord) = parts.next() {
+            x = aoc::parse_token(number, line, coord)?;
+        }
+        
+        let mut y = 0;
+        if let Some(coord) = parts.next() {
+            y = aoc::parse_token(number, line, coord)?;
+        }
+        
+        let mut z = 0;
+        if let Some(coord) = parts.next() {
+            z = aoc::parse_token(number, line, coord)?;
+        }
+
+        self.beacons.push((x, y, z));
+        return Ok(());
+    }
+}
+
+impl Solution for Day19 {
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Day19, AocError> {
+        let mut day = Day19 {
+            scanners: vec![],
+        };
+
+        for (number, line) in aoc::numbered_lines(input) {
+            if line.starts_with("---") {
+                day.scanners.push(Scanner::new());
+            } else if !line.is_empty() {
+                match day.scanners.last_mut() {
+                    Some(last) => last.add_beacon(number, line)?,
+                    None => return Err(AocError::parse(number, 1, "No scanner header found")),
+                };
+            }
+        }
+
+        return Ok(day);
+    }
+
+    fn part_1(&self) -> Result<i64, AocError> {
+        return solve_part_1(self);
+    }
+
+    fn part_2(&self) -> Result<i64, AocError> {
+        return solve_part_2(self);
+    }
+}
+
+fn find_overlap(all_beacons: &mut HashSet<Point>, scanner: &Scanner) -> (bool, Point) {
+    let rotations: Vec<Box<dyn Fn(Point) -> Point>> = vec![
+        // Positive x
+        Box::new(|point: Point| ( point.0,  point.1,  point.2)),
+        Box::new(|point: Point| ( point.0, -point.2,  point.1)),
+        Box::new(|point: Point| ( point.0, -point.1, -point.2)),
+        Box::new(|point: Point| ( point.0,  point.2, -point.1)),
+
+        // Negative X
+        Box::new(|point: Point| (-point.0, -point.1,  point.2)),
+        Box::new(|point: Point| (-point.0,  point.2,  point.1)),
+        Box::new(|point: Point| (-point.0,  point.1, -point.2)),
+        Box::new(|point: Point| (-point.0, -point.2, -point.1)),
+
+        // Positive Y
+        Box::new(|point: Point| ( point.1,  point.2,  point.0)),
+        Box::new(|point: Point| ( point.1, -point.0,  point.2)),
+        Box::new(|point: Point| ( point.1, -point.2, -point.0)),
+        Box::new(|point: Point| ( point.1,  point.0, -point.2)),
+
+        // Negative Y
+        Box::new(|point: Point| (-point.1, -point.2,  point.0)),
+        Box::new(|point: Point| (-point.1,  point.0,  point.2)),
+        Box::new(|point: Point| (-point.1,  point.2, -point.0)),
+        Box::new(|point: Point| (-point.1, -point.0, -point.2)),
+
+        // Positive Z
+        Box::new(|point: Point| ( point.2,  point.0,  point.1)),
+        Box::new(|point: Point| ( point.2, -point.1,  point.0)),
+        Box::new(|point: Point| ( point.2, -point.0, -point.1)),
+        Box::new(|point: Point| ( point.2,  point.1, -point.0)),
+
+        // Negative Z
+        Box::new(|point: Point| (-point.2, -point.0,  point.1)),
+        Box::new(|point: Point| (-point.2,  point.1,  point.0)),
+        Box::new(|point: Point| (-point.2,  point.0, -point.1)),
+        Box::new(|point: Point| (-point.2, -point.1, -point.0)),
+    ];
+
+    for rotation in &rotations {
+        let rotation_fn = rotation;
+
+        for known_point in all_beacons.iter() {
+            for fixed_point in &scanner.beacons {
+                let rotated_point = rotation_fn(*fixed_point);
+
+                let dx = known_point.0 - rotated_point.0;
+                let dy = known_point.1 - rotated_point.1;
+                let dz = known_point.2 - rotated_point.2;
+
+                let mut matched_beacons = 0;
+                for test_point in &scanner.beacons {
+                    let p = rotation_fn(*test_point);
+                    let q = (p.0 + dx, p.1 + dy, p.2 + dz);
+
+                    if all_beacons.contains(&q) {
+                        matched_beacons += 1;
+                        if matched_beacons >= 12 {
+                            for point in &scanner.beacons {
+                                let p = rotation_fn(*point);
+                                let q = (p.0 + dx, p.1 + dy, p.2 + dz);
+                    
+                                all_beacons.insert(q);
+                            }
+
+                            return (true, (dx, dy, dz));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    return (false, (0, 0 ,0));
+}
+
+fn align(day: &Day19) -> Result<(HashSet<Point>, Vec<Point>), AocError> {
+    if day.scanners.is_empty() {
+        return Err(AocError::NoSolution);
+    }
+
+    let mut scanner_locations = vec![(0, 0, 0)];
+
+    let mut all_beacons = HashSet::new();
+    for beacon in &day.scanners[0].beacons {
+        all_beacons.insert(*beacon);
+    }
+
+    let mut all_scanners = VecDeque::new();
+    for i in 1..day.scanners.len() {
+        all_scanners.push_back(&day.scanners[i]);
+    }
+
+    // Counts the scanners retried since the last match, once every
+    // remaining scanner has been retried none of them will ever match.
+    let mut retries = 0;
+    while !all_scanners.is_empty() {
+        if retries > all_scanners.len() {
+            return Err(AocError::NoSolution);
+        }
+
+        if let Some(scanner) = all_scanners.pop_front() {
+            let (found, scanner_location) =
+                find_overlap(&mut all_beacons, scanner);
+
+            if !found {
+                retries += 1;
+                all_scanners.push_back(scanner);
+            } else {
+                retries = 0;
+                scanner_locations.push(scanner_location);
+            }
+        }
+    }
+
+    return Ok((all_beacons, scanner_locations));
+}
+
+fn solve_part_1(day: &Day19) -> Result<i64, AocError> {
+    let (all_beacons, _) = align(day)?;
+    return Ok(all_beacons.len() as i64);
+}
+
+fn solve_part_2(day: &Day19) -> Result<i64, AocError> {
+    let (_, scanner_locations) = align(day)?;
+
+    let mut max_distance = i64::MIN;
+    for i in 0..scanner_locations.len() {
+        let first = &scanner_locations[i];
+
+        for j in i..scanner_locations.len() {
+            let second = &scanner_locations[j];
+
+            let distance_x = (first.0 - second.0).abs();
+            let distance_y = (first.1 - second.1).abs();
+            let distance_z = (first.2 - second.2).abs();
+            let distance = distance_x + distance_y + distance_z;
+
+            if distance > max_distance {
+                max_distance = distance;
+            }
+        }
+    }
+
+    return Ok(max_distance);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/2021/examples/day_19.txt");
+
+    #[test]
+    fn part_1_example() {
+        let day = Day19::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part_1().unwrap(), 79);
+    }
+
+    #[test]
+    fn part_2_example() {
+        let day = Day19::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part_2().unwrap(), 3621);
+    }
+}
@@ -0,0 +1,135 @@
+use crate as aoc;
+use crate::AocError;
+use crate::Grid;
+use crate::Solution;
+
+pub struct Day20 {
+    input_image: Grid<char>,
+    image_enhancement_algorithm: Vec<char>,
+}
+
+impl Solution for Day20 {
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Day20, AocError> {
+        let lines: Vec<&str> = input.lines().collect();
+
+        if lines.is_empty() {
+            return Err(aoc::missing_token(1, "", "No image enhancement algorithm found"));
+        }
+
+        let image_enhancement_algorithm: Vec<char> = lines[0].chars().collect();
+
+        // Every 3x3 neighborhood is a 9 bit index into the algorithm.
+        if image_enhancement_algorithm.len() != 512 {
+            return Err(AocError::parse(1, 1, "Expected 512 image enhancement rules"));
+        }
+
+        if lines.len() < 3 {
+            return Err(aoc::missing_token(lines.len() + 1, "", "No input image found"));
+        }
+
+        let pixels = aoc::numbered_lines(input).skip(2);
+        let input_image = Grid::from_lines(pixels, |ch| ['#', '.'].contains(&ch).then_some(ch))?;
+
+        return Ok(Day20 {
+            input_image: input_image,
+            image_enhancement_algorithm: image_enhancement_algorithm,
+        });
+    }
+
+    fn part_1(&self) -> Result<i64, AocError> {
+        return solve_part_1(self);
+    }
+
+    fn part_2(&self) -> Result<i64, AocError> {
+        return solve_part_2(self);
+    }
+}
+
+// Reads the 3x3 neighborhood around (x, y) of the output image,
+// which is offset by one pixel from the input image it grew from.
+fn enhance(image: &Grid<char>, x: usize, y: usize, outside: char) -> usize {
+    let mut index = 0;
+    for dy in -1..=1 {
+        for dx in -1..=1 {
+            let mut ch = outside;
+            if let Some(point) = image.offset((x, y), dx - 1, dy - 1) {
+                ch = image[point];
+            }
+
+            index *= 2;
+            if ch == '#' {
+                index += 1;
+            }
+        }
+    }
+
+    return index;
+}
+
+fn enhance_repeatedly(day: &Day20, steps: usize) -> i64 {
+    let mut outside = '.';
+    let outside_swaps = [
+        day.image_enhancement_algorithm[0],
+        day.image_enhancement_algorithm[511],
+    ];
+
+    let mut input_image = day.input_image.clone();
+    for _ in 0..steps {
+        // Only the pixels within one of the input image are affected
+        // by it, everything further out is part of the background.
+        let width = input_image.width + 2;
+        let height = input_image.height + 2;
+        let output_image = Grid::from_fn(width, height, |(x, y)| {
+            let index = enhance(&input_image, x, y, outside);
+            return day.image_enhancement_algorithm[index];
+        });
+
+        // The infinite background is uniformly `outside`, so
+        // it enhances to either the first or the last rule.
+        outside = match outside {
+            '#' => outside_swaps[1],
+            _ => outside_swaps[0],
+        };
+
+        input_image = output_image;
+    }
+
+    return input_image.iter().filter(|pixel| **pixel == '#').count() as i64;
+}
+
+fn solve_part_1(day: &Day20) -> Result<i64, AocError> {
+    return Ok(enhance_repeatedly(day, 2));
+}
+
+fn solve_part_2(day: &Day20) -> Result<i64, AocError> {
+    return Ok(enhance_repeatedly(day, 50));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..###..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###.######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#..#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#......#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#.....####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.......##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#
+
+#..#.
+#....
+##..#
+..#..
+..###";
+
+    #[test]
+    fn part_1_example() {
+        let day = Day20::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part_1().unwrap(), 35);
+    }
+
+    #[test]
+    fn part_2_example() {
+        let day = Day20::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part_2().unwrap(), 3351);
+    }
+}
@@ -0,0 +1,246 @@
+use crate as aoc;
+use crate::instrument;
+use crate::AocError;
+use crate::Solution;
+
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::collections::HashMap;
+
+pub struct Day21 {
+    players: Vec<Player>,
+}
+
+#[derive(Clone)]
+struct Player {
+    score: i64,
+    position: i64,
+}
+
+impl Solution for Day21 {
+    type Part1 = i64;
+    type Part2 = u128;
+
+    fn parse(input: &str) -> Result<Day21, AocError> {
+        let initial_player = Player {
+            score: 0,
+            position: 0,
+        };
+
+        let mut day = Day21 {
+            players: vec![initial_player; 2],
+        };
+
+        let mut last = 0;
+        for (number, line) in aoc::numbered_lines(input) {
+            last = number;
+            for (index, player) in day.players.iter_mut().enumerate() {
+                let prefix = format!("Player {} starting position: ", index + 1);
+                if let Some(rest) = line.strip_prefix(&prefix) {
+                    player.position = aoc::parse_token(number, line, rest)?;
+                    if !(1..=10).contains(&player.position) {
+                        let message = format!("Expected a position from 1 to 10, found {}", player.position);
+                        return Err(AocError::parse(number, aoc::column_of(line, rest), &message));
+                    }
+                }
+            }
+        }
+
+        // A position of 0 is never valid, so it marks a missing player.
+        for (index, player) in day.players.iter().enumerate() {
+            if player.position == 0 {
+                let message = format!("No starting position found for player {}", index + 1);
+                return Err(AocError::parse(last + 1, 1, &message));
+            }
+        }
+
+        return Ok(day);
+    }
+
+    fn part_1(&self) -> Result<i64, AocError> {
+        return solve_part_1(self);
+    }
+
+    fn part_2(&self) -> Result<u128, AocError> {
+        return solve_part_2(self);
+    }
+}
+
+fn solve_part_1(day: &Day21) -> Result<i64, AocError> {
+    let mut turns = 0;
+    let mut deterministic_dice = 1;
+    let mut players = day.players.clone();
+    loop {
+        let index = turns % 2;
+        let moves = (3 * deterministic_dice) + 3;
+
+        players[index].position += moves;
+        while players[index].position > 10 {
+            players[index].position -= 10;
+        }
+
+        players[index].score += players[index].position;
+
+        turns += 1;
+        deterministic_dice += 3;
+        while deterministic_dice > 100 {
+            deterministic_dice -= 100;
+        }
+
+        if players[index].score >= 1000 {
+            break;
+        }
+    }
+
+    let mut score = 0;
+    let rolls = (turns as i64) * 3;
+    if players[0].score >= 1000 {
+        score = players[1].score * rolls;
+    } else if players[1].score >= 1000 {
+        score = players[0].score * rolls;
+    }
+
+    return Ok(score);
+}
+
+#[derive(Clone)]
+struct State {
+    scores: [i64; 2],
+    positions: [i64; 2],
+}
+
+impl State {
+    fn from(day: &Day21) -> State {
+        return State {
+            scores: [0; 2],
+            positions: [
+                day.players[0].position,
+                day.players[1].position
+            ],
+        };
+    }
+}
+
+impl Eq for State { }
+
+impl PartialEq for State {
+    fn eq(&self, other: &State) -> bool {
+        return self.scores[0] == other.scores[0]
+            && self.scores[1] == other.scores[1]
+            && self.positions[0] == other.positions[0]
+            && self.positions[1] == other.positions[1];
+    }
+}
+
+impl Hash for State {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.scores[0].hash(state);
+        self.scores[1].hash(state);
+        self.positions[0].hash(state);
+        self.positions[1].hash(state);
+    }
+}
+
+trait IncrementBy<K, V> {
+    fn increment_by(&mut self, key: &K, amount: V);
+}
+
+impl IncrementBy<State, u128> for HashMap<State, u128> {
+    fn increment_by(&mut self, key: &State, amount: u128) {
+        let entry = self.entry(key.clone());
+        let value = entry.or_insert(0);
+        *value += amount;
+    }
+}
+
+fn num_copies(dirac_dice: i64) -> u128 {
+    let copies = HashMap::from([
+        (3, 1), (4, 3), (5, 6),
+        (6, 7), (7, 6), (8, 3), (9, 1),
+    ]);
+
+    return match copies.get(&dirac_dice) {
+        Some(value) => *value,
+        None => panic!("Invalid dirac dice roll"),
+    };
+}
+
+// The number of universes grows exponentially with the
+// number of turns, so it is counted as unsigned 128 bits.
+fn solve_part_2(day: &Day21) -> Result<u128, AocError> {
+    let mut wins = [0; 2];
+    let mut universes = HashMap::from([
+        (State::from(day), 1),
+    ]);
+
+    while !universes.is_empty() {
+        for player in 0..=1 {
+            let mut new_universes = HashMap::new();
+            let mut rolled: u128 = 0;
+            for dirac_dice in 3..=9 {
+                let copies = num_copies(dirac_dice);
+    
+                for universe in universes.iter() {
+                    let count = universe.1 * copies;
+                    rolled += count;
+                    let mut state = universe.0.clone();
+
+                    state.positions[player] += dirac_dice;
+                    while state.positions[player] > 10 {
+                        state.positions[player] -= 10;
+                    }
+
+                    state.scores[player] += state.positions[player];
+                    if state.scores[player] >= 21 {
+                        wins[player] += count;
+                    } else {
+                        new_universes.increment_by(&state, count);
+                    }
+                }
+            }
+
+            // Counted once per turn to keep the counter out of
+            // the loop, saturating like the counter itself does.
+            instrument::count("universes", u64::try_from(rolled).unwrap_or(u64::MAX));
+            instrument::count("distinct states", new_universes.len() as u64);
+            universes = new_universes;
+        }
+    }
+
+    wins.sort();
+    return Ok(wins[1]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Player 1 starting position: 4
+Player 2 starting position: 8";
+
+    #[test]
+    fn part_1_example() {
+        let day = Day21::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part_1().unwrap(), 739785);
+    }
+
+    #[test]
+    fn part_2_example() {
+        let day = Day21::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part_2().unwrap(), 444356092776315);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let examples = [
+            ("", "line 1, column 1: No starting position found for player 1"),
+            ("Player 1 starting position: 4", "line 2, column 1: No starting position found for player 2"),
+            ("Player 1 starting position: 11", "line 1, column 29: Expected a position from 1 to 10, found 11"),
+        ];
+
+        for (input, expected) in examples {
+            assert_eq!(Day21::parse(input).err().unwrap().to_string(), expected, "{}", input);
+        }
+    }
+}
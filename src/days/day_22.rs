@@ -0,0 +1,315 @@
+use crate as aoc;
+use crate::AocError;
+use crate::Solution;
+
+use std::cmp::min;
+use std::cmp::max;
+
+pub struct Day22 {
+    reboot_steps: Vec<RebootStep>,
+}
+
+#[derive(Clone)]
+struct Region {
+    x: (i64, i64),
+    y: (i64, i64),
+    z: (i64, i64),
+}
+
+struct RebootStep {
+    action: String,
+    region: Region,
+}
+
+fn parse_range(number: usize, line: &str, range: &str) -> Result<(i64, i64), AocError> {
+    let mut parts = range.split("..");
+
+    let lower_bound = match parts.next() {
+        Some(value) => aoc::parse_token(number, line, value)?,
+        None => return Err(aoc::missing_token(number, line, "No lower bound found")),
+    };
+
+    let upper_bound = match parts.next() {
+        Some(value) => aoc::parse_token(number, line, value)?,
+        None => return Err(aoc::missing_token(number, line, "No upper bound found")),
+    };
+
+    return Ok((lower_bound, upper_bound));
+}
+
+fn parse_axis(number: usize, line: &str, equation: Option<&str>, prefix: &str) -> Result<(i64, i64), AocError> {
+    let equation = match equation {
+        Some(value) => value,
+        None => return Err(aoc::missing_token(number, line, &format!("No `{}` range found", prefix))),
+    };
+
+    return match equation.strip_prefix(prefix) {
+        Some(value) => parse_range(number, line, value),
+        None => {
+            let message = format!("Expected `{}` but found `{}`", prefix, equation);
+            Err(AocError::parse(number, aoc::column_of(line, equation), &message))
+        },
+    };
+}
+
+impl Solution for Day22 {
+    type Part1 = u128;
+    type Part2 = u128;
+
+    fn parse(input: &str) -> Result<Day22, AocError> {
+        let mut day = Day22 {
+            reboot_steps: vec![],
+        };
+
+        for (number, line) in aoc::numbered_lines(input) {
+            let mut parts = line.split(" ");
+
+            let mut reboot_step = RebootStep {
+                action: match parts.next() {
+                    Some(value) if value == "on" || value == "off" => value.to_string(),
+                    _ => return Err(AocError::parse(number, 1, "No action found")),
+                },
+                region: Region {
+                    x: (0, 0), y: (0, 0), z: (0, 0),
+                },
+            };
+
+            let mut ranges = match parts.next() {
+                Some(value) => value.split(","),
+                None => return Err(aoc::missing_token(number, line, "No ranges found")),
+            };
+
+            reboot_step.region.x = parse_axis(number, line, ranges.next(), "x=")?;
+            reboot_step.region.y = parse_axis(number, line, ranges.next(), "y=")?;
+            reboot_step.region.z = parse_axis(number, line, ranges.next(), "z=")?;
+
+            day.reboot_steps.push(reboot_step);
+        }
+
+        return Ok(day);
+    }
+
+    fn part_1(&self) -> Result<u128, AocError> {
+        return solve_part_1(self);
+    }
+
+    fn part_2(&self) -> Result<u128, AocError> {
+        return solve_part_2(self);
+    }
+}
+
+struct State {
+    count: i128,
+    region: Region,
+}
+
+impl State {
+    fn from(count: i128, region: &Region) -> State {
+        return State {
+            count: count,
+            region: region.clone(),
+        };
+    }
+}
+
+fn has_overlap(first: (i64, i64), second: (i64, i64)) -> bool {
+    return (first.0 <= second.1) && (first.1 >= second.0);
+}
+
+fn find_overlap(first: &Region, second: &Region) -> Option<Region> {
+    if has_overlap(first.x, second.x) {
+        if has_overlap(first.y, second.y) {
+            if has_overlap(first.z, second.z) {
+                let common = Region {
+                    x: (max(first.x.0, second.x.0), min(first.x.1, second.x.1)),
+                    y: (max(first.y.0, second.y.0), min(first.y.1, second.y.1)),
+                    z: (max(first.z.0, second.z.0), min(first.z.1, second.z.1)),
+                };
+            
+                return Some(common);
+            }
+        }
+    }
+
+    return None;
+}
+
+// Coordinates in the millions already overflow an
+// i64 here, so volumes are multiplied in 128 bits.
+fn volume(region: &Region) -> i128 {
+    return (region.x.1 - region.x.0 + 1).abs() as i128
+         * (region.y.1 - region.y.0 + 1).abs() as i128
+         * (region.z.1 - region.z.0 + 1).abs() as i128;
+}
+
+// The signed counts cancel each other out, so
+// a negative total can only be a bug above.
+fn cubes_on(count: i128) -> Result<u128, AocError> {
+    return match u128::try_from(count) {
+        Ok(value) => Ok(value),
+        Err(_) => Err(AocError::NoSolution),
+    };
+}
+
+fn apply_step(reboot_step: &RebootStep, states: &mut Vec<State>) {
+    let mut overlaps = vec![];
+    for state in states.iter() {
+        if let Some(common) = find_overlap(&state.region, &reboot_step.region) {
+            // This accounts for both "on" double covers and "off" subtractions.
+            overlaps.push(State::from(-state.count, &common));
+        }
+    }
+
+    for state in overlaps {
+        states.push(state);
+    }
+
+    if reboot_step.action == "on" {
+        states.push(State::from(1, &reboot_step.region));
+    }
+}
+
+fn solve_part_1(day: &Day22) -> Result<u128, AocError> {
+    let mut states: Vec<State> = vec![];
+    for reboot_step in &day.reboot_steps {
+        apply_step(reboot_step, &mut states);
+    }
+
+    let consider_region = Region {
+        x: (-50, 50), y: (-50, 50), z: (-50, 50),
+    };
+
+    let mut count = 0;
+    for state in &states {
+        if let Some(common) = find_overlap(&state.region, &consider_region) {
+            count += state.count * volume(&common);
+        }
+    }
+
+    return cubes_on(count);
+}
+
+fn solve_part_2(day: &Day22) -> Result<u128, AocError> {
+    let mut states: Vec<State> = vec![];
+    for reboot_step in &day.reboot_steps {
+        apply_step(reboot_step, &mut states);
+    }
+
+    let mut count = 0;
+    for state in &states {
+        count += state.count * volume(&state.region);
+    }
+
+    return cubes_on(count);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SMALL_EXAMPLE: &str = "\
+on x=10..12,y=10..12,z=10..12
+on x=11..13,y=11..13,z=11..13
+off x=9..11,y=9..11,z=9..11
+on x=10..10,y=10..10,z=10..10";
+
+    const LARGER_EXAMPLE: &str = "\
+on x=-20..26,y=-36..17,z=-47..7
+on x=-20..33,y=-21..23,z=-26..28
+on x=-22..28,y=-29..23,z=-38..16
+on x=-46..7,y=-6..46,z=-50..-1
+on x=-49..1,y=-3..46,z=-24..28
+on x=2..47,y=-22..22,z=-23..27
+on x=-27..23,y=-28..26,z=-21..29
+on x=-39..5,y=-6..47,z=-3..44
+on x=-30..21,y=-8..43,z=-13..34
+on x=-22..26,y=-27..20,z=-29..19
+off x=-48..-32,y=26..41,z=-47..-37
+on x=-12..35,y=6..50,z=-50..-2
+off x=-48..-32,y=-32..-16,z=-15..-5
+on x=-18..26,y=-33..15,z=-7..46
+off x=-40..-22,y=-38..-28,z=23..41
+on x=-16..35,y=-41..10,z=-47..6
+off x=-32..-23,y=11..30,z=-14..3
+on x=-49..-5,y=-3..45,z=-29..18
+off x=18..30,y=-20..-8,z=-3..13
+on x=-41..9,y=-7..43,z=-33..15
+on x=-54112..-39298,y=-85059..-49293,z=-27449..7877
+on x=967..23432,y=45373..81175,z=27513..53682";
+
+    const REBOOT_EXAMPLE: &str = include_str!("../../inputs/2021/examples/day_22.txt");
+
+    #[test]
+    fn part_1_examples() {
+        let examples = [
+            (SMALL_EXAMPLE, 39),
+            (LARGER_EXAMPLE, 590784),
+            (REBOOT_EXAMPLE, 474140),
+        ];
+
+        for (input, expected) in examples {
+            let day = Day22::parse(input).unwrap();
+            assert_eq!(day.part_1().unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn part_2_example() {
+        let day = Day22::parse(REBOOT_EXAMPLE).unwrap();
+        assert_eq!(day.part_2().unwrap(), 2758514936282235);
+    }
+
+    fn region(x: (i64, i64), y: (i64, i64), z: (i64, i64)) -> Region {
+        return Region { x: x, y: y, z: z };
+    }
+
+    #[test]
+    fn find_overlap_of_intersecting_regions() {
+        let first = region((10, 12), (10, 12), (10, 12));
+        let second = region((11, 13), (11, 13), (11, 13));
+
+        let common = find_overlap(&first, &second).unwrap();
+        assert_eq!((common.x, common.y, common.z), ((11, 12), (11, 12), (11, 12)));
+        assert_eq!(volume(&common), 8);
+    }
+
+    #[test]
+    fn find_overlap_of_touching_regions() {
+        let first = region((0, 5), (0, 5), (0, 5));
+        let second = region((5, 9), (-3, 0), (2, 3));
+
+        let common = find_overlap(&first, &second).unwrap();
+        assert_eq!((common.x, common.y, common.z), ((5, 5), (0, 0), (2, 3)));
+    }
+
+    #[test]
+    fn find_overlap_of_nested_regions() {
+        let outer = region((-50, 50), (-50, 50), (-50, 50));
+        let inner = region((-5, 47), (-31, 22), (-19, 33));
+
+        let common = find_overlap(&outer, &inner).unwrap();
+        assert_eq!((common.x, common.y, common.z), (inner.x, inner.y, inner.z));
+    }
+
+    #[test]
+    fn find_overlap_of_disjoint_regions() {
+        let first = region((0, 5), (0, 5), (0, 5));
+
+        assert!(find_overlap(&first, &region((6, 9), (0, 5), (0, 5))).is_none());
+        assert!(find_overlap(&first, &region((0, 5), (-9, -1), (0, 5))).is_none());
+        assert!(find_overlap(&first, &region((0, 5), (0, 5), (6, 6))).is_none());
+    }
+
+    #[test]
+    fn parse_errors() {
+        let examples = [
+            ("on", "line 1, column 3: No ranges found"),
+            ("on x=1..2,y=1..2", "line 1, column 17: No `z=` range found"),
+            ("on x=1..2,z=1..2,y=1..2", "line 1, column 11: Expected `y=` but found `z=1..2`"),
+        ];
+
+        for (input, expected) in examples {
+            assert_eq!(Day22::parse(input).err().unwrap().to_string(), expected, "{}", input);
+        }
+    }
+}
@@ -0,0 +1,383 @@
+use crate as aoc;
+use crate::instrument;
+use crate::search;
+use crate::search::SearchSpace;
+use crate::AocError;
+use crate::Grid;
+use crate::Solution;
+
+use std::cmp::{min, max};
+
+pub struct Day23 {
+    burrow: Burrow,
+    unfolded_burrow: Burrow,
+}
+
+type Point = (usize, usize);
+
+#[derive(Clone, Eq, Hash, PartialEq)]
+struct Amphipod {
+    letter: char,
+    location: Point,
+}
+
+#[derive(Clone)]
+struct Burrow {
+    layout: Grid<char>,
+    amphipods: Vec<Amphipod>,
+}
+
+fn parse_burrow(lines: &[&str]) -> Result<Burrow, AocError> {
+    // The lines below the hallway stop after the last wall,
+    // so pad them with spaces to get a rectangular layout.
+    let width = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+    let lines: Vec<String> = lines.iter().map(|line| format!("{:width$}", line, width = width)).collect();
+
+    let numbered_lines = lines.iter().enumerate().map(|(i, line)| (i + 1, line.as_str()));
+    let mut burrow = Burrow {
+        layout: Grid::from_lines(numbered_lines, Some)?,
+        amphipods: vec![],
+    };
+
+    for (x, y) in burrow.layout.points() {
+        let ch = burrow.layout[(x, y)];
+        if ch.is_alphabetic() {
+            if !('A'..='D').contains(&ch) {
+                let message = format!("Unknown amphipod found: `{}`", ch);
+                return Err(AocError::parse(y + 1, x + 1, &message));
+            }
+
+            burrow.layout[(x, y)] = '.';
+            burrow.amphipods.push(Amphipod {
+                letter: ch,
+                location: (x, y),
+            });
+        }
+    }
+
+    return Ok(burrow);
+}
+
+impl Solution for Day23 {
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Day23, AocError> {
+        let mut lines: Vec<&str> = input.lines().collect();
+        if lines.len() != 5 {
+            return Err(aoc::missing_token(lines.len() + 1, "", "Expected a folded burrow of 5 lines"));
+        }
+
+        let burrow = parse_burrow(&lines)?;
+
+        // Between the first and second lines of text that contain
+        // amphipod starting positions, insert the folded lines.
+        lines.insert(3, "  #D#C#B#A#");
+        lines.insert(4, "  #D#B#A#C#");
+        let unfolded_burrow = parse_burrow(&lines)?;
+
+        return Ok(Day23 {
+            burrow: burrow,
+            unfolded_burrow: unfolded_burrow,
+        });
+    }
+
+    fn part_1(&self) -> Result<i64, AocError> {
+        return solve_part_1(self);
+    }
+
+    fn part_2(&self) -> Result<i64, AocError> {
+        return solve_part_2(self);
+    }
+}
+
+fn is_complete(amphipods: &[Amphipod]) -> bool {
+    for amphipod in amphipods.iter() {
+        // @Robustness: Assume other rules are in place to prevent
+        // an amphipod from being in the hallway in front of a room.
+
+        if amphipod.letter == 'A' {
+            if amphipod.location.0 != 3 {
+                return false;
+            }
+        } else if amphipod.letter == 'B' {
+            if amphipod.location.0 != 5 {
+                return false;
+            }
+        } else if amphipod.letter == 'C' {
+            if amphipod.location.0 != 7 {
+                return false;
+            }
+        } else if amphipod.letter == 'D' {
+            if amphipod.location.0 != 9 {
+                return false;
+            }
+        }
+    }
+
+    return true;
+}
+
+fn can_move(amphipods: &[Amphipod], this: &Amphipod, x: usize, y: usize, part: i64) -> Option<i64> {
+    // Rule:
+    //  Amphipods will never stop on the space immediately outside any room.
+    //  Handled implicitly, by never asking can_move(...) on those coordinates.
+
+    // Rule:
+    //  Amphipods will never move from the hallway into a room unless that room
+    //  is their destination room and that room contains no amphipods which do
+    //  not also have that room as their own destination.
+
+    for amphipod in amphipods.iter() {
+        if amphipod.letter != this.letter {
+            if amphipod.location == (x, y + 1) {
+                return None;
+            }
+            
+            if part == 2 {
+                if amphipod.location == (x, y + 2) {
+                    return None;
+                } else if amphipod.location == (x, y + 3) {
+                    return None;
+                }
+            }
+        }
+    }
+
+    // Rule:
+    //  Once an amphipod stops moving in the hallway, it will stay in that spot
+    //  until it can move into a room.
+
+    if (y == 1) && (this.location.1 == 1) {
+        return None;
+    }
+
+    for amphipod in amphipods.iter() {
+        if amphipod.location == (x, y) {
+            return None; // Destination already occupied
+        }
+    }
+
+    for yt in 1..this.location.1 {
+        for amphipod in amphipods.iter() {
+            if amphipod.location == (this.location.0, yt) {
+                return None; // Path from source to Hallway blocked
+            }
+        }
+    }
+
+    let min_x = min(x, this.location.0);
+    let max_x = max(x, this.location.0);
+    for xt in min_x..=max_x {
+        if xt == this.location.0 {
+            continue;
+        }
+        
+        for amphipod in amphipods.iter() {
+            if amphipod.location == (xt, 1) {
+                return None; // Path through the Hallway blocked
+            }
+        }
+    }
+
+    for yt in 1..y {
+        for amphipod in amphipods.iter() {
+            if amphipod.location == (x, yt) {
+                return None; // Path from Hallway to destination blocked
+            }
+        }
+    }
+
+    let to_hallway = (this.location.1 - 1) as i64;
+    let to_correct_x = ((this.location.0 as i64) - (x as i64)).abs();
+    let from_hallway = (y - 1) as i64;
+    
+    let factor = 10_i64.pow((this.letter as u32) - ('A' as u32));
+    return Some(factor * (to_hallway + to_correct_x + from_hallway));
+}
+
+fn is_in_correct_place(amphipods: &[Amphipod], this: &Amphipod, part: i64) -> bool {
+    if this.letter == 'A' {
+        if this.location.0 != 3 {
+            return false;
+        }
+    } else if this.letter == 'B' {
+        if this.location.0 != 5 {
+            return false;
+        }
+    } else if this.letter == 'C' {
+        if this.location.0 != 7 {
+            return false;
+        }
+    } else if this.letter == 'D' {
+        if this.location.0 != 9 {
+            return false;
+        }
+    }
+
+    let mut last_y = 3;
+    if part == 2 { last_y = 5; }
+
+    let x = this.location.0;
+    let y = this.location.1;
+    if (2 <= y) && (y < last_y) {
+        let mut amphipods_below_i = vec![];
+        let mut has_amphipods_below = false;
+        for i in 0..amphipods.len() {
+            if amphipods[i].location == (x, y + 1) {
+                amphipods_below_i.push(i);
+                has_amphipods_below = true;
+            }
+            
+            if part == 2 {
+                if amphipods[i].location == (x, y + 2) {
+                    amphipods_below_i.push(i);
+                    has_amphipods_below = true;
+                } else if amphipods[i].location == (x, y + 3) {
+                    amphipods_below_i.push(i);
+                    has_amphipods_below = true;
+                }
+            }
+        }
+
+        if !has_amphipods_below {
+            return false;
+        }
+    
+        if has_amphipods_below {
+            for i in &amphipods_below_i {
+                let amphipod_below = &amphipods[*i];
+                if amphipod_below.letter != this.letter {
+                    return false;
+                }
+            }
+        }
+    }
+
+    return true;
+}
+
+struct Rearrangement {
+    part: i64,
+}
+
+// The amphipods are kept sorted by location, so two states
+// that only swap amphipods of the same type are equal.
+impl SearchSpace for Rearrangement {
+    type State = Vec<Amphipod>;
+
+    fn neighbors(&self, amphipods: &Vec<Amphipod>) -> Vec<(Vec<Amphipod>, i64)> {
+        let part = self.part;
+        instrument::count("states expanded", 1);
+
+        let mut neighbors = vec![];
+        for i in 0..amphipods.len() {
+            let amphipod = &amphipods[i];
+            if is_in_correct_place(amphipods, amphipod, part) {
+                continue;
+            }
+
+            let mut moves_to_check = vec![
+                ( 1, 1), ( 2, 1),
+                ( 4, 1), ( 6, 1), ( 8, 1),
+                (10, 1), (11, 1),
+            ];
+
+            if amphipod.letter == 'A' {
+                moves_to_check.push((3, 2));
+                moves_to_check.push((3, 3));
+
+                if part == 2 {
+                    moves_to_check.push((3, 4));
+                    moves_to_check.push((3, 5));
+                }
+            } else if amphipod.letter == 'B' {
+                moves_to_check.push((5, 2));
+                moves_to_check.push((5, 3));
+
+                if part == 2 {
+                    moves_to_check.push((5, 4));
+                    moves_to_check.push((5, 5));
+                }
+            }  else if amphipod.letter == 'C' {
+                moves_to_check.push((7, 2));
+                moves_to_check.push((7, 3));
+
+                if part == 2 {
+                    moves_to_check.push((7, 4));
+                    moves_to_check.push((7, 5));
+                }
+            } else if amphipod.letter == 'D' {
+                moves_to_check.push((9, 2));
+                moves_to_check.push((9, 3));
+
+                if part == 2 {
+                    moves_to_check.push((9, 4));
+                    moves_to_check.push((9, 5));
+                }
+            }
+
+            for (x, y) in moves_to_check.iter() {
+                if let Some(energy) = can_move(amphipods, amphipod, *x, *y, part) {
+                    let mut new_state = amphipods.clone();
+                    new_state[i].location = (*x, *y);
+                    new_state.sort_by_key(|amphipod| amphipod.location);
+
+                    neighbors.push((new_state, energy));
+                }
+            }
+        }
+
+        return neighbors;
+    }
+
+    fn is_goal(&self, amphipods: &Vec<Amphipod>) -> bool {
+        return is_complete(amphipods);
+    }
+}
+
+fn organize(burrow: &Burrow, part: i64) -> Result<i64, AocError> {
+    let mut amphipods = burrow.amphipods.clone();
+    amphipods.sort_by_key(|amphipod| amphipod.location);
+
+    let span = instrument::span("search");
+    let path = search::dijkstra(&Rearrangement { part: part }, amphipods);
+    span.stop();
+
+    return match path {
+        Some(value) => Ok(value.cost),
+        None => Err(AocError::NoSolution),
+    };
+}
+
+fn solve_part_1(day: &Day23) -> Result<i64, AocError> {
+    return organize(&day.burrow, 1);
+}
+
+fn solve_part_2(day: &Day23) -> Result<i64, AocError> {
+    return organize(&day.unfolded_burrow, 2);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+#############
+#...........#
+###B#C#B#D###
+  #A#D#C#A#
+  #########";
+
+    #[test]
+    fn part_1_example() {
+        let day = Day23::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part_1().unwrap(), 12521);
+    }
+
+    #[test]
+    fn part_2_example() {
+        let day = Day23::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part_2().unwrap(), 44169);
+    }
+}
@@ -0,0 +1,206 @@
+use crate as aoc;
+use crate::AocError;
+use crate::Solution;
+
+pub struct Day24 {
+    dependencies: Vec<Dependency>,
+}
+
+enum Fsm {
+    FindInputW,
+    FindPushOrPop,
+    ScanPushConstant,
+    ExtractPushConstant,
+    ExtractPopConstant,
+}
+
+struct State {
+    inp: i64,
+    constant: i64,
+}
+
+struct Dependency {
+    source: usize,
+    target: usize,
+    constraint: i64,
+}
+
+impl Solution for Day24 {
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Day24, AocError> {
+        let mut day = Day24 {
+            dependencies: vec![],
+        };
+
+        let mut states = vec![];
+
+        let mut current_w = -1;
+        let mut current_state = Fsm::FindInputW;
+        for (number, instruction) in aoc::numbered_lines(input) {
+            match current_state {
+                Fsm::FindInputW => {
+                    if instruction == "inp w" {
+                        current_w += 1;
+                        current_state = Fsm::FindPushOrPop;
+                    }
+                },
+                Fsm::FindPushOrPop => {
+                    if instruction == "div z 1" {
+                        current_state = Fsm::ScanPushConstant;
+                    } else if instruction == "div z 26" {
+                        current_state = Fsm::ExtractPopConstant;
+                    }
+                },
+                Fsm::ScanPushConstant => {
+                    // There are multiple `add y ...` instructions, but the
+                    // push constant is always immediately after the `add y w`.
+                    if instruction == "add y w" {
+                        current_state = Fsm::ExtractPushConstant;
+                    }
+                },
+                Fsm::ExtractPushConstant => {
+                    if let Some(expr) = instruction.strip_prefix("add y ") {
+                        current_state = Fsm::FindInputW;
+
+                        let constant: i64 = aoc::parse_token(number, instruction, expr)?;
+
+                        states.push(State {
+                            inp: current_w,
+                            constant: constant,
+                        });
+                    }
+                },
+                Fsm::ExtractPopConstant => {
+                    if let Some(expr) = instruction.strip_prefix("add x ") {
+                        let constant: i64 = aoc::parse_token(number, instruction, expr)?;
+
+                        if let Some(top) = states.pop() {
+                            current_state = Fsm::FindInputW;
+
+                            day.dependencies.push(Dependency {
+                                source: top.inp as usize,
+                                target: current_w as usize,
+                                constraint: top.constant + constant,
+                            });
+                        }
+                    }
+                },
+            }
+        }
+
+        return Ok(day);
+    }
+
+    fn part_1(&self) -> Result<i64, AocError> {
+        return solve_part_1(self);
+    }
+
+    fn part_2(&self) -> Result<i64, AocError> {
+        return solve_part_2(self);
+    }
+}
+
+fn solve_part_1(day: &Day24) -> Result<i64, AocError> {
+    let mut digits = vec![0; 14];
+    for dependency in &day.dependencies {
+        for i in (1..=9).rev() { // Iterate from highest to lowest.
+            let w = i + dependency.constraint;
+            if (1..=9).contains(&w) {
+                digits[dependency.source] = i;
+                digits[dependency.target] = w;
+                break;
+            }
+        }
+    }
+
+    let mut model_number =  0;
+    for digit in &digits {
+        model_number *= 10;
+        model_number += digit;
+    }
+
+    return Ok(model_number);
+}
+
+fn solve_part_2(day: &Day24) -> Result<i64, AocError> {
+    let mut digits = vec![0; 14];
+    for dependency in &day.dependencies {
+        for i in 1..=9 { // Iterate from lowest to highest.
+            let w = i + dependency.constraint;
+            if (1..=9).contains(&w) {
+                digits[dependency.source] = i;
+                digits[dependency.target] = w;
+                break;
+            }
+        }
+    }
+
+    let mut model_number =  0;
+    for digit in &digits {
+        model_number *= 10;
+        model_number += digit;
+    }
+
+    return Ok(model_number);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Mirrors one 18 instruction block of the MONAD program, of
+    // which the parser only reads the divisor and the constants.
+    fn block(divisor: i64, x_constant: i64, y_constant: i64) -> String {
+        let instructions = [
+            String::from("inp w"),
+            String::from("mul x 0"),
+            String::from("add x z"),
+            String::from("mod x 26"),
+            format!("div z {}", divisor),
+            format!("add x {}", x_constant),
+            String::from("eql x w"),
+            String::from("eql x 0"),
+            String::from("mul y 0"),
+            String::from("add y 25"),
+            String::from("mul y x"),
+            String::from("add y 1"),
+            String::from("mul z y"),
+            String::from("mul y 0"),
+            String::from("add y w"),
+            format!("add y {}", y_constant),
+            String::from("mul y x"),
+            String::from("add z y"),
+        ];
+
+        return instructions.join("\n");
+    }
+
+    fn example() -> String {
+        // Push 3 then pop -5, so the second digit is the first minus 2.
+        return [block(1, 15, 3), block(26, -5, 10)].join("\n");
+    }
+
+    #[test]
+    fn parse_dependencies() {
+        let day = Day24::parse(&example()).unwrap();
+
+        assert_eq!(day.dependencies.len(), 1);
+        assert_eq!(day.dependencies[0].source, 0);
+        assert_eq!(day.dependencies[0].target, 1);
+        assert_eq!(day.dependencies[0].constraint, -2);
+    }
+
+    #[test]
+    fn part_1_example() {
+        let day = Day24::parse(&example()).unwrap();
+        assert_eq!(day.part_1().unwrap(), 97_000_000_000_000);
+    }
+
+    #[test]
+    fn part_2_example() {
+        let day = Day24::parse(&example()).unwrap();
+        assert_eq!(day.part_2().unwrap(), 31_000_000_000_000);
+    }
+}
@@ -0,0 +1,118 @@
+use crate::grid::Point;
+use crate::AocError;
+use crate::Grid;
+use crate::Solution;
+
+// The sea floor wraps around: sea cucumbers moving off the
+// east or south edge reappear on the west or north edge.
+type SeaFloor = Grid<char>;
+
+#[derive(Clone)]
+pub struct Day25 {
+    sea_floor: SeaFloor,
+}
+
+impl Solution for Day25 {
+    type Part1 = i64;
+    type Part2 = &'static str;
+
+    fn parse(input: &str) -> Result<Day25, AocError> {
+        let sea_floor = Grid::parse(input, |ch| ['>', 'v', '.'].contains(&ch).then_some(ch))?;
+
+        return Ok(Day25 {
+            sea_floor: sea_floor.with_wrapping(),
+        });
+    }
+
+    fn part_1(&self) -> Result<i64, AocError> {
+        return solve_part_1(self);
+    }
+
+    fn part_2(&self) -> Result<&'static str, AocError> {
+        return solve_part_2(self);
+    }
+}
+
+struct Movement {
+    source: Point,
+    target: Point,
+}
+
+fn step(sea_floor: &mut SeaFloor) -> bool {
+    let mut moved = false;
+    moved |= step_herd(sea_floor, '>', (1, 0));
+    moved |= step_herd(sea_floor, 'v', (0, 1));
+    return moved;
+}
+
+// The whole herd looks ahead before any of them
+// moves, so they all move at the same time.
+fn step_herd(sea_floor: &mut SeaFloor, herd: char, direction: (i64, i64)) -> bool {
+    let mut movements = vec![];
+    for source in sea_floor.points() {
+        if sea_floor[source] != herd {
+            continue;
+        }
+
+        if let Some(target) = sea_floor.offset(source, direction.0, direction.1) {
+            if sea_floor[target] == '.' {
+                movements.push(Movement {
+                    source: source,
+                    target: target,
+                });
+            }
+        }
+    }
+
+    for movement in &movements {
+        sea_floor[movement.target] = herd;
+        sea_floor[movement.source] = '.';
+    }
+
+    return !movements.is_empty();
+}
+
+fn solve_part_1(day: &Day25) -> Result<i64, AocError> {
+    let mut clone = day.clone();
+
+    let mut steps = 1;
+    while step(&mut clone.sea_floor) {
+        steps += 1;
+    }
+
+    return Ok(steps);
+}
+
+fn solve_part_2(_day: &Day25) -> Result<&'static str, AocError> {
+    // There is no second puzzle on the last day,
+    // the final star is awarded for all the others.
+    return Ok("Merry Christmas!");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+v...>>.vv>
+.vv>>.vv..
+>>.>v>...v
+>>v>>.>.v.
+v>v.vv.v..
+>.>>..v...
+.vv..>.>v.
+v.v..>>v.v
+....v..v.>";
+
+    #[test]
+    fn part_1_example() {
+        let day = Day25::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part_1().unwrap(), 58);
+    }
+
+    #[test]
+    fn part_2_example() {
+        let day = Day25::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part_2().unwrap(), "Merry Christmas!");
+    }
+}
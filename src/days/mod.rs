@@ -0,0 +1,81 @@
+use crate::Puzzle;
+
+pub mod day_01;
+pub mod day_02;
+pub mod day_03;
+pub mod day_04;
+pub mod day_05;
+pub mod day_06;
+pub mod day_07;
+pub mod day_08;
+pub mod day_09;
+pub mod day_10;
+pub mod day_11;
+pub mod day_12;
+pub mod day_13;
+pub mod day_14;
+pub mod day_15;
+pub mod day_16;
+pub mod day_17;
+pub mod day_18;
+pub mod day_19;
+pub mod day_20;
+pub mod day_21;
+pub mod day_22;
+pub mod day_23;
+pub mod day_24;
+pub mod day_25;
+
+use day_01::Day01;
+use day_02::Day02;
+use day_03::Day03;
+use day_04::Day04;
+use day_05::Day05;
+use day_06::Day06;
+use day_07::Day07;
+use day_08::Day08;
+use day_09::Day09;
+use day_10::Day10;
+use day_11::Day11;
+use day_12::Day12;
+use day_13::Day13;
+use day_14::Day14;
+use day_15::Day15;
+use day_16::Day16;
+use day_17::Day17;
+use day_18::Day18;
+use day_19::Day19;
+use day_20::Day20;
+use day_21::Day21;
+use day_22::Day22;
+use day_23::Day23;
+use day_24::Day24;
+use day_25::Day25;
+
+pub const PUZZLES: [Puzzle; 25] = [
+    Puzzle::new::<Day01>(1, "inputs/2021/day_01.txt"),
+    Puzzle::new::<Day02>(2, "inputs/2021/day_02.txt"),
+    Puzzle::new::<Day03>(3, "inputs/2021/day_03.txt"),
+    Puzzle::new::<Day04>(4, "inputs/2021/day_04.txt"),
+    Puzzle::new::<Day05>(5, "inputs/2021/day_05.txt"),
+    Puzzle::new::<Day06>(6, "inputs/2021/day_06.txt"),
+    Puzzle::new::<Day07>(7, "inputs/2021/day_07.txt"),
+    Puzzle::new::<Day08>(8, "inputs/2021/day_08.txt"),
+    Puzzle::new::<Day09>(9, "inputs/2021/day_09.txt"),
+    Puzzle::new::<Day10>(10, "inputs/2021/day_10.txt"),
+    Puzzle::new::<Day11>(11, "inputs/2021/day_11.txt"),
+    Puzzle::new::<Day12>(12, "inputs/2021/day_12.txt"),
+    Puzzle::new::<Day13>(13, "inputs/2021/day_13.txt"),
+    Puzzle::new::<Day14>(14, "inputs/2021/day_14.txt"),
+    Puzzle::new::<Day15>(15, "inputs/2021/day_15.txt"),
+    Puzzle::new::<Day16>(16, "inputs/2021/day_16.txt"),
+    Puzzle::new::<Day17>(17, "inputs/2021/day_17.txt"),
+    Puzzle::new::<Day18>(18, "inputs/2021/day_18.txt"),
+    Puzzle::new::<Day19>(19, "inputs/2021/day_19.txt"),
+    Puzzle::new::<Day20>(20, "inputs/2021/day_20.txt"),
+    Puzzle::new::<Day21>(21, "inputs/2021/day_21.txt"),
+    Puzzle::new::<Day22>(22, "inputs/2021/day_22.txt"),
+    Puzzle::new::<Day23>(23, "inputs/2021/day_23.txt"),
+    Puzzle::new::<Day24>(24, "inputs/2021/day_24.txt"),
+    Puzzle::new::<Day25>(25, "inputs/2021/day_25.txt"),
+];
@@ -0,0 +1,69 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+#[derive(Debug)]
+pub enum AocError {
+    Io {
+        path: String,
+        source: io::Error,
+    },
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    Http {
+        url: String,
+        message: String,
+    },
+    NoSolution,
+}
+
+impl AocError {
+    pub fn io(path: &str, source: io::Error) -> AocError {
+        return AocError::Io {
+            path: path.to_string(),
+            source: source,
+        };
+    }
+
+    pub fn http(url: &str, message: &str) -> AocError {
+        return AocError::Http {
+            url: url.to_string(),
+            message: message.to_string(),
+        };
+    }
+
+    // Lines and columns are both 1-based, so they
+    // can be matched up with what an editor shows.
+    pub fn parse(line: usize, column: usize, message: &str) -> AocError {
+        return AocError::Parse {
+            line: line,
+            column: column,
+            message: message.to_string(),
+        };
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            AocError::Io { path, source } => write!(f, "{}: {}", path, source),
+            AocError::Parse { line, column, message } => {
+                write!(f, "line {}, column {}: {}", line, column, message)
+            },
+            AocError::Http { url, message } => write!(f, "{}: {}", url, message),
+            AocError::NoSolution => write!(f, "No solution found"),
+        };
+    }
+}
+
+impl Error for AocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        return match self {
+            AocError::Io { source, .. } => Some(source),
+            _ => None,
+        };
+    }
+}
@@ -0,0 +1,268 @@
+use crate as aoc;
+use crate::AocError;
+
+use std::env;
+use std::fs;
+use std::io;
+use std::io::Write;
+use std::path::Path;
+use std::process::Command;
+use std::process::Stdio;
+use std::thread;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+pub const SESSION_FILE: &str = ".session";
+
+// The puzzle inputs are static, so there is never a
+// reason to hit adventofcode.com in a tight loop.
+pub const MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+const USER_AGENT: &str = "advent-of-code-2021 runner by Brian Rowlett";
+
+pub struct Fetcher {
+    pub base_url: String,
+    pub session: String,
+    pub min_interval: Duration,
+    pub timestamp_file: String,
+}
+
+impl Fetcher {
+    // The base URL can be overridden with `AOC_BASE_URL`
+    // to point it at a local stand-in server instead.
+    pub fn new(session: &str) -> Fetcher {
+        return Fetcher {
+            base_url: match env::var("AOC_BASE_URL") {
+                Ok(value) => value,
+                Err(_) => BASE_URL.to_string(),
+            },
+            session: session.to_string(),
+            min_interval: MIN_INTERVAL,
+            timestamp_file: String::from("inputs/.last_fetch"),
+        };
+    }
+
+    pub fn url(&self, year: i64, day: i64) -> String {
+        return format!("{}/{}/day/{}/input", self.base_url.trim_end_matches('/'), year, day);
+    }
+
+    pub fn fetch(&self, year: i64, day: i64, file_name: &str) -> Result<(), AocError> {
+        if Path::new(file_name).exists() {
+            let reason = io::Error::new(io::ErrorKind::AlreadyExists, "Input already downloaded");
+            return Err(AocError::io(file_name, reason));
+        }
+
+        self.wait_for_rate_limit();
+
+        let url = self.url(year, day);
+        let input = self.request(&url, &[]);
+        self.record_request()?;
+
+        write_input(file_name, &input?)?;
+        return Ok(());
+    }
+
+    // Returns the page the website answers with, which
+    // `submit::parse_response` turns into an outcome.
+    pub fn submit_answer(&self, year: i64, day: i64, part: i64, answer: &str) -> Result<String, AocError> {
+        self.wait_for_rate_limit();
+
+        let url = format!("{}/{}/day/{}/answer", self.base_url.trim_end_matches('/'), year, day);
+        let level = format!("level={}", part);
+        let answer = format!("answer={}", answer);
+
+        let response = self.request(&url, &["--data", &level, "--data-urlencode", &answer]);
+        self.record_request()?;
+
+        return response;
+    }
+
+    // Requests are made by the `curl` executable, which has to be on
+    // `PATH`, so that the crate does not need an HTTP client of its own.
+    fn request(&self, url: &str, arguments: &[&str]) -> Result<String, AocError> {
+        // The session cookie goes through stdin, so
+        // it does not show up in the process list.
+        let child = Command::new("curl")
+            .args(["--silent", "--show-error", "--fail", "--header", "@-", "--user-agent", USER_AGENT])
+            .args(arguments)
+            .arg(url)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn();
+
+        let mut child = match child {
+            Ok(value) => value,
+            Err(reason) if reason.kind() == io::ErrorKind::NotFound => {
+                return Err(AocError::http(url, "curl not found, fetch and submit need curl on PATH"));
+            },
+            Err(reason) => return Err(AocError::http(url, &format!("Failed to run curl: {}", reason))),
+        };
+
+        if let Some(mut stdin) = child.stdin.take() {
+            if let Err(reason) = writeln!(stdin, "Cookie: session={}", self.session) {
+                return Err(AocError::http(url, &format!("Failed to send the session: {}", reason)));
+            }
+        }
+
+        let output = match child.wait_with_output() {
+            Ok(value) => value,
+            Err(reason) => return Err(AocError::http(url, &format!("Failed to run curl: {}", reason))),
+        };
+
+        if !output.status.success() {
+            return Err(AocError::http(url, String::from_utf8_lossy(&output.stderr).trim()));
+        }
+
+        return match String::from_utf8(output.stdout) {
+            Ok(value) => Ok(value),
+            Err(_) => Err(AocError::http(url, "Response is not valid UTF-8")),
+        };
+    }
+
+    // The time of the last request is kept in a file so
+    // the limit also holds across separate invocations.
+    fn wait_for_rate_limit(&self) {
+        let last = match fs::read_to_string(&self.timestamp_file) {
+            Ok(value) => value.trim().parse::<u64>().unwrap_or(0),
+            Err(_) => return,
+        };
+
+        let elapsed = Duration::from_millis(now_millis().saturating_sub(last));
+        if elapsed < self.min_interval {
+            thread::sleep(self.min_interval - elapsed);
+        }
+    }
+
+    fn record_request(&self) -> Result<(), AocError> {
+        return match fs::write(&self.timestamp_file, now_millis().to_string()) {
+            Ok(_) => Ok(()),
+            Err(reason) => Err(AocError::io(&self.timestamp_file, reason)),
+        };
+    }
+}
+
+pub fn session() -> Option<String> {
+    if let Ok(value) = env::var("AOC_SESSION") {
+        return Some(value.trim().to_string());
+    }
+
+    return match aoc::load(SESSION_FILE) {
+        Ok(value) => Some(value.trim().to_string()),
+        Err(_) => None,
+    };
+}
+
+fn now_millis() -> u64 {
+    return match SystemTime::now().duration_since(UNIX_EPOCH) {
+        Ok(value) => value.as_millis() as u64,
+        Err(_) => 0,
+    };
+}
+
+// Writes to a temporary file first, so an interrupted
+// download never leaves a truncated input behind.
+fn write_input(file_name: &str, input: &str) -> Result<(), AocError> {
+    if let Some(parent) = Path::new(file_name).parent() {
+        if let Err(reason) = fs::create_dir_all(parent) {
+            return Err(AocError::io(file_name, reason));
+        }
+    }
+
+    let partial = format!("{}.partial", file_name);
+    if let Err(reason) = fs::write(&partial, input) {
+        return Err(AocError::io(&partial, reason));
+    }
+
+    return match fs::rename(&partial, file_name) {
+        Ok(_) => Ok(()),
+        Err(reason) => Err(AocError::io(file_name, reason)),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock;
+
+    use std::path::PathBuf;
+    use std::process;
+    use std::time::Instant;
+
+    fn scratch(name: &str) -> PathBuf {
+        let directory = env::temp_dir().join(format!("aoc-fetch-{}-{}", process::id(), name));
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(&directory).unwrap();
+        return directory;
+    }
+
+    fn fetcher(base_url: &str, directory: &Path) -> Fetcher {
+        return Fetcher {
+            base_url: base_url.to_string(),
+            session: String::from("secret"),
+            min_interval: Duration::ZERO,
+            timestamp_file: directory.join(".last_fetch").to_string_lossy().to_string(),
+        };
+    }
+
+    #[test]
+    fn fetch_downloads_and_caches_input() {
+        let directory = scratch("downloads");
+        let (url, server) = mock::serve(vec![(200, "199\n200\n208\n")]);
+
+        let file_name = directory.join("inputs").join("day_07.txt");
+        let file_name = file_name.to_str().unwrap();
+        fetcher(&url, &directory).fetch(2021, 7, file_name).unwrap();
+
+        assert_eq!(fs::read_to_string(file_name).unwrap(), "199\n200\n208\n");
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2021/day/7/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains("Cookie: session=secret\r\n"));
+    }
+
+    #[test]
+    fn fetch_refuses_to_download_again() {
+        let directory = scratch("refuses");
+        let file_name = directory.join("day_01.txt");
+        fs::write(&file_name, "cached").unwrap();
+
+        // Nothing is listening here, so any request would fail loudly.
+        let error = fetcher("http://127.0.0.1:9", &directory).fetch(2021, 1, file_name.to_str().unwrap()).unwrap_err();
+
+        assert!(error.to_string().ends_with("Input already downloaded"));
+        assert_eq!(fs::read_to_string(&file_name).unwrap(), "cached");
+    }
+
+    #[test]
+    fn fetch_reports_http_errors() {
+        let directory = scratch("errors");
+        let (url, server) = mock::serve(vec![(400, "Puzzle inputs differ by user.  Please log in to get your puzzle input.")]);
+
+        let file_name = directory.join("day_02.txt");
+        let error = fetcher(&url, &directory).fetch(2021, 2, file_name.to_str().unwrap()).unwrap_err();
+
+        assert!(error.to_string().contains("/2021/day/2/input: "));
+        assert!(error.to_string().contains("400"));
+        assert!(!file_name.exists());
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn fetch_waits_between_requests() {
+        let directory = scratch("waits");
+        let (url, server) = mock::serve(vec![(200, "1"), (200, "2")]);
+
+        let mut fetcher = fetcher(&url, &directory);
+        fetcher.min_interval = Duration::from_millis(300);
+
+        let now = Instant::now();
+        fetcher.fetch(2021, 1, directory.join("day_01.txt").to_str().unwrap()).unwrap();
+        fetcher.fetch(2021, 2, directory.join("day_02.txt").to_str().unwrap()).unwrap();
+
+        assert!(now.elapsed() >= Duration::from_millis(300));
+        server.join().unwrap();
+    }
+}
@@ -0,0 +1,274 @@
+use crate as aoc;
+use crate::AocError;
+
+use std::fmt;
+use std::ops::Index;
+use std::ops::IndexMut;
+
+pub type Point = (usize, usize);
+
+const OFFSETS_4: [(i64, i64); 4] = [
+              (0, -1),
+    (-1,  0),          (1,  0),
+              (0,  1),
+];
+
+const OFFSETS_8: [(i64, i64); 8] = [
+    (-1, -1), (0, -1), (1, -1),
+    (-1,  0),          (1,  0),
+    (-1,  1), (0,  1), (1,  1),
+];
+
+// Cells are stored row by row, so (x, y)
+// lives at index y * width + x.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Grid<T> {
+    pub width: usize,
+    pub height: usize,
+    cells: Vec<T>,
+    wrapping: bool,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Grid<T> where T: Clone {
+        return Grid {
+            width: width,
+            height: height,
+            cells: vec![value; width * height],
+            wrapping: false,
+        };
+    }
+
+    pub fn from_fn<F>(width: usize, height: usize, mut f: F) -> Grid<T>
+    where
+        F: FnMut(Point) -> T,
+    {
+        let mut cells = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                cells.push(f((x, y)));
+            }
+        }
+
+        return Grid {
+            width: width,
+            height: height,
+            cells: cells,
+            wrapping: false,
+        };
+    }
+
+    pub fn parse<F>(input: &str, mapper: F) -> Result<Grid<T>, AocError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        return Grid::from_lines(aoc::numbered_lines(input), mapper);
+    }
+
+    // Takes numbered lines rather than the whole input, so a
+    // grid below a header still reports the right line numbers.
+    pub fn from_lines<'a, I, F>(lines: I, mut mapper: F) -> Result<Grid<T>, AocError>
+    where
+        I: Iterator<Item = (usize, &'a str)>,
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut grid = Grid {
+            width: 0,
+            height: 0,
+            cells: vec![],
+            wrapping: false,
+        };
+
+        for (number, line) in lines {
+            let mut width = 0;
+            for (column, ch) in line.char_indices() {
+                match mapper(ch) {
+                    Some(value) => grid.cells.push(value),
+                    None => {
+                        let message = format!("Unknown symbol found: `{}`", ch);
+                        return Err(AocError::parse(number, column + 1, &message));
+                    },
+                };
+
+                width += 1;
+            }
+
+            if grid.height == 0 {
+                grid.width = width;
+            } else if width != grid.width {
+                let message = format!("Expected a row of {} cells", grid.width);
+                return Err(aoc::missing_token(number, line, &message));
+            }
+
+            grid.height += 1;
+        }
+
+        return Ok(grid);
+    }
+
+    // On a wrapping grid the edges are glued together, so
+    // stepping off one side comes back in on the other.
+    pub fn with_wrapping(mut self) -> Grid<T> {
+        self.wrapping = true;
+        return self;
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        if point.0 >= self.width || point.1 >= self.height {
+            return None;
+        }
+
+        return Some(&self.cells[point.1 * self.width + point.0]);
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        if point.0 >= self.width || point.1 >= self.height {
+            return None;
+        }
+
+        return Some(&mut self.cells[point.1 * self.width + point.0]);
+    }
+
+    pub fn offset(&self, point: Point, dx: i64, dy: i64) -> Option<Point> {
+        if self.cells.is_empty() {
+            return None;
+        }
+
+        let x = point.0 as i64 + dx;
+        let y = point.1 as i64 + dy;
+
+        if self.wrapping {
+            let x = x.rem_euclid(self.width as i64);
+            let y = y.rem_euclid(self.height as i64);
+            return Some((x as usize, y as usize));
+        }
+
+        if !(0..self.width as i64).contains(&x) || !(0..self.height as i64).contains(&y) {
+            return None;
+        }
+
+        return Some((x as usize, y as usize));
+    }
+
+    pub fn neighbors_4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        return OFFSETS_4.iter().filter_map(move |(dx, dy)| self.offset(point, *dx, *dy));
+    }
+
+    pub fn neighbors_8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        return OFFSETS_8.iter().filter_map(move |(dx, dy)| self.offset(point, *dx, *dy));
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        return (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)));
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        return self.cells.iter();
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        return &self.cells[(y * self.width)..((y + 1) * self.width)];
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        return self.cells.iter().skip(x).step_by(self.width.max(1)).take(self.height);
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        return match self.get(point) {
+            Some(value) => value,
+            None => panic!("{:?} is outside of the {}x{} grid", point, self.width, self.height),
+        };
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        return match self.get_mut(point) {
+            Some(value) => value,
+            None => panic!("{:?} is outside of the {}x{} grid", point, width, height),
+        };
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for y in 0..self.height {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            for cell in self.row(y) {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+123
+456";
+
+    fn digits(input: &str) -> Grid<u32> {
+        return Grid::parse(input, |ch| ch.to_digit(10)).unwrap();
+    }
+
+    #[test]
+    fn parse_and_display_round_trip() {
+        let grid = digits(EXAMPLE);
+
+        assert_eq!((grid.width, grid.height), (3, 2));
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.to_string(), EXAMPLE);
+    }
+
+    #[test]
+    fn parse_error_location() {
+        let error = Grid::parse("123\n4x6", |ch| ch.to_digit(10)).unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 2: Unknown symbol found: `x`");
+
+        let error = Grid::parse("123\n45", |ch| ch.to_digit(10)).unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 3: Expected a row of 3 cells");
+    }
+
+    #[test]
+    fn neighbors_at_the_edges() {
+        let grid = digits(EXAMPLE);
+
+        let neighbors: Vec<Point> = grid.neighbors_4((0, 0)).collect();
+        assert_eq!(neighbors, [(1, 0), (0, 1)]);
+
+        let neighbors: Vec<Point> = grid.neighbors_8((1, 1)).collect();
+        assert_eq!(neighbors, [(0, 0), (1, 0), (2, 0), (0, 1), (2, 1)]);
+    }
+
+    #[test]
+    fn neighbors_with_wrapping() {
+        let grid = digits(EXAMPLE).with_wrapping();
+
+        let neighbors: Vec<Point> = grid.neighbors_4((0, 0)).collect();
+        assert_eq!(neighbors, [(0, 1), (2, 0), (1, 0), (0, 1)]);
+        assert_eq!(grid.offset((2, 1), 1, 1), Some((0, 0)));
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = digits(EXAMPLE);
+
+        assert_eq!(grid.row(1), [4, 5, 6]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<u32>>(), [2, 5]);
+    }
+}
@@ -0,0 +1,188 @@
+use std::alloc::GlobalAlloc;
+use std::alloc::Layout;
+use std::alloc::System;
+use std::cell::RefCell;
+use std::mem;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+use std::time::Instant;
+
+// Spans and counters with the same name are added up, and are
+// listed in the order they were first seen. Counters saturate.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Report {
+    pub spans: Vec<(&'static str, Duration)>,
+    pub counters: Vec<(&'static str, u64)>,
+}
+
+impl Report {
+    pub fn is_empty(&self) -> bool {
+        return self.spans.is_empty() && self.counters.is_empty();
+    }
+}
+
+// Each thread collects its own report, so
+// days running side by side do not mix.
+thread_local! {
+    static REPORT: RefCell<Report> = RefCell::new(Report::default());
+}
+
+pub struct Span {
+    name: &'static str,
+    start: Instant,
+}
+
+impl Span {
+    pub fn stop(self) {
+        // Recorded by drop below.
+    }
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        let elapsed = self.start.elapsed();
+        REPORT.with(|report| {
+            let spans = &mut report.borrow_mut().spans;
+            match spans.iter_mut().find(|(name, _)| *name == self.name) {
+                Some((_, total)) => *total += elapsed,
+                None => spans.push((self.name, elapsed)),
+            };
+        });
+    }
+}
+
+// Times everything until the returned span
+// is stopped or goes out of scope.
+pub fn span(name: &'static str) -> Span {
+    return Span {
+        name: name,
+        start: Instant::now(),
+    };
+}
+
+pub fn count(name: &'static str, amount: u64) {
+    REPORT.with(|report| {
+        let counters = &mut report.borrow_mut().counters;
+        match counters.iter_mut().find(|(counter, _)| *counter == name) {
+            Some((_, total)) => *total = total.saturating_add(amount),
+            None => counters.push((name, amount)),
+        };
+    });
+}
+
+// Returns everything recorded on this thread
+// so far, and starts over with an empty report.
+pub fn take() -> Report {
+    return REPORT.with(|report| mem::take(&mut *report.borrow_mut()));
+}
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+// Keeps track of the bytes currently allocated and the highest
+// that has been since `reset_peak`. Only takes effect once the
+// binary installs it with `#[global_allocator]`.
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let pointer = unsafe { System.alloc(layout) };
+        if !pointer.is_null() {
+            grow(layout.size());
+        }
+
+        return pointer;
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let pointer = unsafe { System.alloc_zeroed(layout) };
+        if !pointer.is_null() {
+            grow(layout.size());
+        }
+
+        return pointer;
+    }
+
+    unsafe fn dealloc(&self, pointer: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(pointer, layout) };
+        ALLOCATED.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, pointer: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_pointer = unsafe { System.realloc(pointer, layout, new_size) };
+        if !new_pointer.is_null() {
+            ALLOCATED.fetch_sub(layout.size(), Ordering::Relaxed);
+            grow(new_size);
+        }
+
+        return new_pointer;
+    }
+}
+
+fn grow(size: usize) {
+    let allocated = ALLOCATED.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(allocated, Ordering::Relaxed);
+}
+
+pub fn allocated() -> usize {
+    return ALLOCATED.load(Ordering::Relaxed);
+}
+
+pub fn peak() -> usize {
+    return PEAK.load(Ordering::Relaxed);
+}
+
+pub fn reset_peak() {
+    PEAK.store(ALLOCATED.load(Ordering::Relaxed), Ordering::Relaxed);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spans_and_counters_add_up() {
+        take();
+
+        span("search").stop();
+        count("states", 2);
+        span("parse").stop();
+        count("states", 3);
+        span("search").stop();
+
+        let report = take();
+        let names: Vec<&str> = report.spans.iter().map(|(name, _)| *name).collect();
+        assert_eq!(names, ["search", "parse"]);
+        assert_eq!(report.counters, [("states", 5)]);
+        assert!(take().is_empty());
+    }
+
+    #[test]
+    fn counters_saturate() {
+        take();
+
+        count("universes", u64::MAX - 1);
+        count("universes", 2);
+
+        assert_eq!(take().counters, [("universes", u64::MAX)]);
+    }
+
+    #[test]
+    fn allocator_tracks_peak() {
+        // The tests run on the system allocator, so
+        // nothing else is counted while this runs.
+        let layout = Layout::from_size_align(1024, 8).unwrap();
+        let before = allocated();
+
+        unsafe {
+            let pointer = CountingAllocator.alloc(layout);
+            assert_eq!(allocated(), before + 1024);
+            assert!(peak() >= before + 1024);
+
+            CountingAllocator.dealloc(pointer, layout);
+        }
+
+        assert_eq!(allocated(), before);
+    }
+}
@@ -0,0 +1,302 @@
+use crate::AocError;
+
+// Just enough JSON to read back the files written by
+// `aoc bench --format json`, without pulling in serde.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    pub fn get(&self, key: &str) -> Option<&Json> {
+        return match self {
+            Json::Object(members) => members.iter().find(|(name, _)| name == key).map(|(_, value)| value),
+            _ => None,
+        };
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        return match self {
+            Json::Number(value) => Some(*value),
+            _ => None,
+        };
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        return match self {
+            Json::String(value) => Some(value.as_str()),
+            _ => None,
+        };
+    }
+
+    pub fn as_array(&self) -> Option<&[Json]> {
+        return match self {
+            Json::Array(values) => Some(values.as_slice()),
+            _ => None,
+        };
+    }
+}
+
+pub fn escape(value: &str) -> String {
+    let mut escaped = String::from("\"");
+    for ch in value.chars() {
+        match ch {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\t' => escaped.push_str("\\t"),
+            ch if (ch as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", ch as u32)),
+            ch => escaped.push(ch),
+        };
+    }
+
+    escaped.push('"');
+    return escaped;
+}
+
+pub fn parse(input: &str) -> Result<Json, AocError> {
+    let mut parser = Parser {
+        chars: input.chars().collect(),
+        index: 0,
+    };
+
+    let value = parser.value()?;
+
+    parser.skip_whitespace();
+    if parser.index < parser.chars.len() {
+        return Err(parser.error("Unexpected text after value"));
+    }
+
+    return Ok(value);
+}
+
+struct Parser {
+    chars: Vec<char>,
+    index: usize,
+}
+
+impl Parser {
+    fn error(&self, message: &str) -> AocError {
+        let mut line = 1;
+        let mut column = 1;
+        for ch in &self.chars[..self.index.min(self.chars.len())] {
+            if *ch == '\n' {
+                line += 1;
+                column = 1;
+            } else {
+                column += 1;
+            }
+        }
+
+        return AocError::parse(line, column, message);
+    }
+
+    fn peek(&self) -> Option<char> {
+        return self.chars.get(self.index).copied();
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(ch) = self.peek() {
+            if !ch.is_whitespace() { break; }
+            self.index += 1;
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), AocError> {
+        self.skip_whitespace();
+        if self.peek() != Some(expected) {
+            return Err(self.error(&format!("Expected `{}`", expected)));
+        }
+
+        self.index += 1;
+        return Ok(());
+    }
+
+    fn keyword(&mut self, keyword: &str, value: Json) -> Result<Json, AocError> {
+        for expected in keyword.chars() {
+            if self.peek() != Some(expected) {
+                return Err(self.error(&format!("Expected `{}`", keyword)));
+            }
+
+            self.index += 1;
+        }
+
+        return Ok(value);
+    }
+
+    fn value(&mut self) -> Result<Json, AocError> {
+        self.skip_whitespace();
+        return match self.peek() {
+            Some('{') => self.object(),
+            Some('[') => self.array(),
+            Some('"') => Ok(Json::String(self.string()?)),
+            Some('t') => self.keyword("true", Json::Bool(true)),
+            Some('f') => self.keyword("false", Json::Bool(false)),
+            Some('n') => self.keyword("null", Json::Null),
+            Some(ch) if ch == '-' || ch.is_ascii_digit() => self.number(),
+            Some(ch) => Err(self.error(&format!("Unexpected symbol: `{}`", ch))),
+            None => Err(self.error("Unexpected end of input")),
+        };
+    }
+
+    fn object(&mut self) -> Result<Json, AocError> {
+        let mut members = vec![];
+
+        self.expect('{')?;
+        self.skip_whitespace();
+        if self.peek() == Some('}') {
+            self.index += 1;
+            return Ok(Json::Object(members));
+        }
+
+        loop {
+            self.skip_whitespace();
+            let key = self.string()?;
+            self.expect(':')?;
+            members.push((key, self.value()?));
+
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => self.index += 1,
+                Some('}') => {
+                    self.index += 1;
+                    return Ok(Json::Object(members));
+                },
+                _ => return Err(self.error("Expected `,` or `}`")),
+            };
+        }
+    }
+
+    fn array(&mut self) -> Result<Json, AocError> {
+        let mut values = vec![];
+
+        self.expect('[')?;
+        self.skip_whitespace();
+        if self.peek() == Some(']') {
+            self.index += 1;
+            return Ok(Json::Array(values));
+        }
+
+        loop {
+            values.push(self.value()?);
+
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => self.index += 1,
+                Some(']') => {
+                    self.index += 1;
+                    return Ok(Json::Array(values));
+                },
+                _ => return Err(self.error("Expected `,` or `]`")),
+            };
+        }
+    }
+
+    fn string(&mut self) -> Result<String, AocError> {
+        if self.peek() != Some('"') {
+            return Err(self.error("Expected `\"`"));
+        }
+
+        self.index += 1;
+
+        let mut value = String::new();
+        loop {
+            let ch = match self.peek() {
+                Some(value) => value,
+                None => return Err(self.error("No closing `\"` found")),
+            };
+
+            self.index += 1;
+            match ch {
+                '"' => return Ok(value),
+                '\\' => {
+                    let escaped = match self.peek() {
+                        Some('"') => '"',
+                        Some('\\') => '\\',
+                        Some('/') => '/',
+                        Some('n') => '\n',
+                        Some('t') => '\t',
+                        Some('r') => '\r',
+                        Some('u') => self.unicode_escape()?,
+                        _ => return Err(self.error("Unknown escape sequence")),
+                    };
+
+                    self.index += 1;
+                    value.push(escaped);
+                },
+                ch => value.push(ch),
+            };
+        }
+    }
+
+    // Reads the four hex digits of a `\uXXXX` escape, leaving
+    // the index on the last digit like the other escapes.
+    fn unicode_escape(&mut self) -> Result<char, AocError> {
+        let start = self.index + 1;
+        let end = start + 4;
+        if end > self.chars.len() {
+            return Err(self.error("Incomplete unicode escape"));
+        }
+
+        let digits: String = self.chars[start..end].iter().collect();
+        let code = match u32::from_str_radix(&digits, 16) {
+            Ok(value) => value,
+            Err(_) => return Err(self.error("Invalid unicode escape")),
+        };
+
+        self.index = end - 1;
+        return match char::from_u32(code) {
+            Some(value) => Ok(value),
+            None => Err(self.error("Invalid unicode escape")),
+        };
+    }
+
+    fn number(&mut self) -> Result<Json, AocError> {
+        let start = self.index;
+        while let Some(ch) = self.peek() {
+            if !(ch.is_ascii_digit() || "+-.eE".contains(ch)) { break; }
+            self.index += 1;
+        }
+
+        let text: String = self.chars[start..self.index].iter().collect();
+        return match text.parse::<f64>() {
+            Ok(value) => Ok(Json::Number(value)),
+            Err(reason) => {
+                self.index = start;
+                Err(self.error(&format!("f64::parse failed: {}", reason)))
+            },
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_nested_values() {
+        let value = parse("{ \"a\": [1, -2.5e1, true, null], \"b\": { \"c\": \"d\" } }").unwrap();
+
+        let a = value.get("a").and_then(Json::as_array).unwrap();
+        assert_eq!(a, &[Json::Number(1.0), Json::Number(-25.0), Json::Bool(true), Json::Null]);
+        assert_eq!(value.get("b").and_then(|b| b.get("c")).and_then(Json::as_str), Some("d"));
+        assert_eq!(value.get("e"), None);
+    }
+
+    #[test]
+    fn escape_round_trip() {
+        let original = "quote \" backslash \\ newline \n tab \t bell \u{7}";
+        assert_eq!(parse(&escape(original)).unwrap(), Json::String(original.to_string()));
+    }
+
+    #[test]
+    fn parse_error_location() {
+        let error = parse("{\n  \"a\": 1,\n  \"b\" 2\n}").unwrap_err();
+        assert_eq!(error.to_string(), "line 3, column 7: Expected `:`");
+    }
+}
@@ -0,0 +1,293 @@
+use std::env;
+use std::fmt::Display;
+use std::fs::File;
+use std::io;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Read;
+use std::path::Path;
+use std::process;
+use std::str::FromStr;
+use std::vec::Vec;
+
+pub mod answer;
+pub mod answers;
+pub mod bench;
+pub mod days;
+pub mod error;
+pub mod fetch;
+pub mod grid;
+pub mod instrument;
+pub mod json;
+pub mod registry;
+pub mod scaffold;
+pub mod search;
+pub mod submit;
+pub mod watch;
+
+#[cfg(test)]
+mod mock;
+
+pub use answer::Answer;
+pub use error::AocError;
+pub use grid::Grid;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Visualization {
+    Terminal,
+    Ppm,
+}
+
+pub trait Solution {
+    type Part1: Into<Answer>;
+    type Part2: Into<Answer>;
+
+    fn parse(input: &str) -> Result<Self, AocError> where Self: Sized;
+
+    // Days that only need one pass over their input read it line by line
+    // with `stream_lines`, the rest get a single string. Streaming saves
+    // the raw text, but only days that solve as they read use less memory.
+    fn parse_stream(reader: &mut dyn BufRead) -> Result<Self, AocError> where Self: Sized {
+        let mut input = String::new();
+        return match reader.read_to_string(&mut input) {
+            Ok(_) => Self::parse(&input),
+            Err(reason) => Err(AocError::io("<input>", reason)),
+        };
+    }
+    fn part_1(&self) -> Result<Self::Part1, AocError>;
+    fn part_2(&self) -> Result<Self::Part2, AocError>;
+
+    // Some days can draw how they found their answer,
+    // the rest keep this default of drawing nothing.
+    fn visualize(&self, _part: i64, _format: Visualization) -> Result<Option<String>, AocError> {
+        return Ok(None);
+    }
+}
+
+pub trait Runnable {
+    fn part_1(&self) -> Result<Answer, AocError>;
+    fn part_2(&self) -> Result<Answer, AocError>;
+    fn visualize(&self, part: i64, format: Visualization) -> Result<Option<String>, AocError>;
+
+    fn solve(&self, part: i64) -> Result<Answer, AocError> {
+        return match part {
+            1 => self.part_1(),
+            _ => self.part_2(),
+        };
+    }
+}
+
+impl<S: Solution> Runnable for S {
+    fn part_1(&self) -> Result<Answer, AocError> {
+        return Solution::part_1(self).map(|value| value.into());
+    }
+
+    fn part_2(&self) -> Result<Answer, AocError> {
+        return Solution::part_2(self).map(|value| value.into());
+    }
+
+    fn visualize(&self, part: i64, format: Visualization) -> Result<Option<String>, AocError> {
+        return Solution::visualize(self, part, format);
+    }
+}
+
+pub type Parser = fn(&mut dyn BufRead) -> Result<Box<dyn Runnable>, AocError>;
+
+pub struct Puzzle {
+    pub day: i64,
+    pub input: &'static str,
+    pub parse: Parser,
+}
+
+impl Puzzle {
+    pub const fn new<S: Solution + 'static>(day: i64, input: &'static str) -> Puzzle {
+        return Puzzle {
+            day: day,
+            input: input,
+            parse: parse_boxed::<S>,
+        };
+    }
+}
+
+fn parse_boxed<S: Solution + 'static>(reader: &mut dyn BufRead) -> Result<Box<dyn Runnable>, AocError> {
+    return match S::parse_stream(reader) {
+        Ok(value) => Ok(Box::new(value)),
+        Err(reason) => Err(reason),
+    };
+}
+
+pub fn load(file_name: &str) -> Result<String, AocError> {
+    let mut contents = String::new();
+
+    // By convention a file name of `-` reads
+    // the puzzle input from stdin instead.
+    if file_name == "-" {
+        return match io::stdin().read_to_string(&mut contents) {
+            Ok(_) => Ok(contents),
+            Err(reason) => Err(AocError::io("<stdin>", reason)),
+        };
+    }
+
+    let path = Path::new(&file_name);
+
+    let mut file = match File::open(path) {
+        Ok(value) => value,
+        Err(reason) => return Err(AocError::io(file_name, reason)),
+    };
+
+    return match file.read_to_string(&mut contents) {
+        Ok(_) => Ok(contents),
+        Err(reason) => Err(AocError::io(file_name, reason)),
+    };
+}
+
+// Like `load`, but leaves the reading to the caller,
+// so that the file never has to be in memory at once.
+pub fn open(file_name: &str) -> Result<Box<dyn BufRead>, AocError> {
+    if file_name == "-" {
+        return Ok(Box::new(io::stdin().lock()));
+    }
+
+    return match File::open(Path::new(file_name)) {
+        Ok(value) => Ok(Box::new(BufReader::new(value))),
+        Err(reason) => Err(AocError::io(file_name, reason)),
+    };
+}
+
+pub fn lines(file_name: &str) -> Result<Vec<String>, AocError> {
+    let mut lines = vec![];
+    let mut stream = stream_lines(open(file_name)?);
+    while let Some(line) = stream.next_line() {
+        lines.push(String::from(line?.1));
+    }
+
+    return Ok(lines);
+}
+
+// Reads one line at a time into the same buffer. This cannot be
+// an `Iterator`, since each line borrows the buffer until the next.
+pub struct LineStream<R> {
+    reader: R,
+    buffer: String,
+    number: usize,
+}
+
+impl<R: BufRead> LineStream<R> {
+    // Numbered from 1 and without the line ending,
+    // the same as the lines of `numbered_lines`.
+    pub fn next_line(&mut self) -> Option<Result<(usize, &str), AocError>> {
+        self.buffer.clear();
+        return match self.reader.read_line(&mut self.buffer) {
+            Ok(0) => None,
+            Ok(_) => {
+                self.number += 1;
+                let line = self.buffer.strip_suffix('\n').unwrap_or(&self.buffer);
+                let line = line.strip_suffix('\r').unwrap_or(line);
+                Some(Ok((self.number, line)))
+            },
+            Err(reason) => Some(Err(AocError::io("<input>", reason))),
+        };
+    }
+}
+
+pub fn stream_lines<R: BufRead>(reader: R) -> LineStream<R> {
+    return LineStream {
+        reader: reader,
+        buffer: String::new(),
+        number: 0,
+    };
+}
+
+pub fn numbered_lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    return input.lines().enumerate().map(|(i, line)| (i + 1, line));
+}
+
+pub fn column_of(line: &str, token: &str) -> usize {
+    // The token must be a slice of the line, so the
+    // distance between the pointers is the byte offset.
+    let offset = (token.as_ptr() as usize).saturating_sub(line.as_ptr() as usize);
+    return offset.min(line.len()) + 1;
+}
+
+pub fn parse_token<T>(number: usize, line: &str, token: &str) -> Result<T, AocError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    return match token.parse::<T>() {
+        Ok(value) => Ok(value),
+        Err(reason) => {
+            let message = format!("String::parse failed: {}", reason);
+            Err(AocError::parse(number, column_of(line, token), &message))
+        },
+    };
+}
+
+pub fn missing_token(number: usize, line: &str, message: &str) -> AocError {
+    return AocError::parse(number, line.len() + 1, message);
+}
+
+// Errors go to stderr and end the process with status 1, but a
+// failing part 1 still lets part 2 have its go before exiting.
+pub fn run<S: Solution>(default_file_name: &str) {
+    let file_name = match env::args().nth(1) {
+        Some(value) => value,
+        None => default_file_name.to_string(),
+    };
+
+    let mut reader = match open(&file_name) {
+        Ok(value) => value,
+        Err(reason) => exit_with(&format!("load failed: {}", reason)),
+    };
+
+    let day = match S::parse_stream(&mut reader) {
+        Ok(value) => value,
+        Err(reason) => exit_with(&format!("parse failed: {}", reason)),
+    };
+
+    let mut failed = false;
+    match day.part_1() {
+        Ok(value) => println!("Part 1: {}", value.into()),
+        Err(reason) => {
+            eprintln!("solve_part_1 failed: {}", reason);
+            failed = true;
+        },
+    }
+
+    match day.part_2() {
+        Ok(value) => println!("Part 2: {}", value.into()),
+        Err(reason) => {
+            eprintln!("solve_part_2 failed: {}", reason);
+            failed = true;
+        },
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
+
+fn exit_with(message: &str) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stream_lines_like_numbered_lines() {
+        let input = "199\r\n200\n\n208";
+        let mut stream = stream_lines(input.as_bytes());
+
+        let mut lines = vec![];
+        while let Some(line) = stream.next_line() {
+            let (number, line) = line.unwrap();
+            lines.push((number, line.to_string()));
+        }
+
+        let expected: Vec<(usize, String)> = numbered_lines(input).map(|(number, line)| (number, line.to_string())).collect();
+        assert_eq!(lines, expected);
+    }
+}
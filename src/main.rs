@@ -1,103 +1,954 @@
 use std::env;
+use std::path::Path;
+use std::panic;
 use std::process;
 use std::process::Command;
 use std::process::Stdio;
-use std::time::Duration;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::mpsc;
+use std::thread;
 use std::time::Instant;
 use colored::*;
+use itertools::EitherOrBoth;
+use itertools::Itertools;
+use aoc::answers::Answers;
+use aoc::bench;
+use aoc::bench::Baseline;
+use aoc::bench::TimedResult;
+use aoc::fetch;
+use aoc::fetch::Fetcher;
+use aoc::instrument::CountingAllocator;
+use aoc::json;
+use aoc::json::Json;
+use aoc::scaffold;
+use aoc::submit;
+use aoc::submit::History;
+use aoc::submit::Outcome;
+use aoc::watch;
+use aoc::watch::Snapshot;
+use aoc::Answer;
+use aoc::AocError;
+use aoc::Puzzle;
+use aoc::Visualization;
+use aoc::registry;
 
-struct TimedResult {
-    day: String,
-    duration: f64,
+// Counts allocations, so benchmarks can
+// report the peak heap usage of each day.
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+// The answers of each year live in `answers/<year>.toml`.
+const ANSWERS: &str = "answers/YEAR.toml";
+const HISTORY: &str = "answers/submissions.tsv";
+
+// Durations (in seconds) below these are colored green and yellow
+// respectively, otherwise red. `--fast` and `--slow` override them.
+const FAST: f64 = 0.5;
+const SLOW: f64 = 1.0;
+
+#[derive(PartialEq)]
+enum Format {
+    Text,
+    Json,
+    Csv,
+    Ppm,
 }
 
-impl TimedResult {
-    fn new(day: &String, duration: Duration) -> TimedResult {
-        return TimedResult {
-            day: day.to_string(),
-            duration: duration.as_secs_f64(),
+struct Options {
+    year: i64,
+    days: Vec<i64>,
+    parts: Vec<i64>,
+    input: Option<String>,
+    format: Format,
+    baseline: Option<String>,
+    threshold: f64,
+    fast: f64,
+    slow: f64,
+    jobs: usize,
+}
+
+impl Options {
+    fn new() -> Options {
+        return Options {
+            year: registry::DEFAULT_YEAR,
+            days: vec![],
+            parts: vec![1, 2],
+            input: None,
+            format: Format::Text,
+            baseline: None,
+            threshold: 10.0,
+            fast: FAST,
+            slow: SLOW,
+            jobs: 1,
+        };
+    }
+
+    fn puzzles(&self) -> Vec<&'static Puzzle> {
+        let mut puzzles = vec![];
+        for puzzle in registry::puzzles(self.year).unwrap_or(&[]) {
+            if self.days.contains(&puzzle.day) {
+                puzzles.push(puzzle);
+            }
+        }
+
+        return puzzles;
+    }
+
+    fn input_for(&self, puzzle: &Puzzle) -> String {
+        return match &self.input {
+            Some(value) => value.to_string(),
+            None => puzzle.input.to_string(),
         };
     }
+
+    fn answers_file(&self) -> String {
+        return ANSWERS.replace("YEAR", &self.year.to_string());
+    }
 }
 
 fn main() {
-    const DAYS: i64 = 25;
+    let args: Vec<String> = env::args().skip(1).collect();
 
-    for arg in env::args() {
+    for arg in &args {
         if arg == "--help" {
             println!("Usage:");
-            println!("    cargo run               # Run performance benchmark");
-            println!("    cargo run --bin day_XY  # Run a specific days executable");
+            println!("    cargo run --release [bench] [DAYS]  # Run performance benchmark");
+            println!("    cargo run run DAYS                  # Run and print the answers");
+            println!("    cargo run verify [DAYS]             # Check the answers against {}", ANSWERS);
+            println!("    cargo run fetch DAYS                # Download missing inputs (needs curl and AOC_SESSION or {})", fetch::SESSION_FILE);
+            println!("    cargo run submit DAY PART           # Submit an answer with curl, recorded in {}", HISTORY);
+            println!("    cargo run visualize DAY             # Draw how a day found its answers (day 15)");
+            println!("    cargo run watch DAY                 # Re-run a day whenever its source or inputs change");
+            println!("    cargo run new YEAR DAY              # Generate the files for a new day");
+            println!("    cargo run --bin day_XY [PATH]       # Run a specific days executable");
+            println!();
+            println!("Days:");
+            println!("    all                                 # Every day (default for bench and verify)");
+            println!("    7                                   # A single day");
+            println!("    3..=9                               # An inclusive range of days");
             println!();
             println!("Options:");
-            println!("    --help                  # Display help information");
-            println!("    --version               # Display version information");
+            println!("    --year YYYY                         # Pick the days of another year (default {})", registry::DEFAULT_YEAR);
+            println!("    --part N                            # Only run part N of each day");
+            println!("    --input PATH                        # Read a single days input from PATH (`-` for stdin)");
+            println!("    --format text|json|csv              # Benchmark output format (default text)");
+            println!("    --format text|ppm                   # Visualization output format (default text)");
+            println!("    --format text|json                  # Answers output format, json includes timings");
+            println!("    --baseline PATH                     # Compare benchmark medians against a saved json run");
+            println!("    --threshold PERCENT                 # Slowdown flagged as a regression (default 10)");
+            println!("    --fast SECONDS                      # Durations below are colored green (default {})", FAST);
+            println!("    --slow SECONDS                      # Durations from here on are colored red (default {})", SLOW);
+            println!("    --jobs N                            # Run days on N threads, for `run` and `verify` (default 1)");
+            println!("    --help                              # Display help information");
+            println!("    --version                           # Display version information");
             process::exit(0);
         } else if arg == "--version" {
-            println!("Advent of Code 2021");
+            let years: Vec<String> = registry::years().iter().map(|year| year.to_string()).collect();
+            println!("Advent of Code {}", years.join(", "));
             println!("Copyright (C) 2021 Brian Rowlett");
             process::exit(0);
         }
     }
 
-    println!("Building...");
-    for day in 1..=DAYS {
-        let binary = format!("day_{:0>2}", day);
+    let command = match args.first() {
+        Some(value) => value.as_str(),
+        None => "bench",
+    };
 
-        let mut build_cmd = Command::new("cargo");
-        build_cmd.stdout(Stdio::null());
-        build_cmd.stderr(Stdio::null());
-        build_cmd.args(["build", "--bin", binary.as_str(), "--release"]);
+    let result = match command {
+        "run" => parse_options(&args[1..], false).and_then(|options| run(&options)),
+        "bench" => parse_options(&args[1..], true).and_then(|options| bench(&options)),
+        "verify" => parse_options(&args[1..], true).and_then(|options| verify(&options)),
+        "fetch" => parse_options(&args[1..], false).and_then(|options| fetch(&options)),
+        "submit" => parse_submit_options(&args[1..]).and_then(|options| submit(&options)),
+        "visualize" => parse_options(&args[1..], false).and_then(|options| visualize(&options)),
+        "watch" => parse_options(&args[1..], false).and_then(|options| watch(&options)),
+        "new" => new(&args[1..]),
+        _ => parse_options(&args, true).and_then(|options| bench(&options)),
+    };
 
-        if let Ok(mut build_process) = build_cmd.spawn() {
-            if let Ok(build_status) = build_process.wait() {
-                if !build_status.success() {
-                    if let Some(build_code) = build_status.code() {
-                        panic!("Build process failed: Day {:0>2} (exit status: {})", day, build_code);
-                    }
-                }
+    if let Err(reason) = result {
+        eprintln!("error: {}", reason);
+        eprintln!("Try `--help` for more information.");
+        process::exit(1);
+    }
+}
+
+fn parse_options(args: &[String], all_by_default: bool) -> Result<Options, String> {
+    let mut options = Options::new();
+    let mut days = None;
+    let (mut fast, mut slow) = (None, None);
+
+    let mut i = 0;
+    while i < args.len() {
+        if args[i] == "--year" {
+            i += 1;
+            options.year = match args.get(i).map(|value| value.parse::<i64>()) {
+                Some(Ok(year)) if registry::puzzles(year).is_some() => year,
+                Some(Ok(year)) => return Err(format!("No solutions for {}", year)),
+                _ => return Err(String::from("--year expects a year, e.g. 2021")),
+            };
+        } else if args[i] == "--part" {
+            i += 1;
+            options.parts = match args.get(i).map(|value| value.parse::<i64>()) {
+                Some(Ok(part)) if (1..=2).contains(&part) => vec![part],
+                _ => return Err(String::from("--part expects 1 or 2")),
+            };
+        } else if args[i] == "--input" {
+            i += 1;
+            options.input = match args.get(i) {
+                Some(value) => Some(value.to_string()),
+                None => return Err(String::from("--input expects a path")),
+            };
+        } else if args[i] == "--format" {
+            i += 1;
+            options.format = match args.get(i).map(|value| value.as_str()) {
+                Some("text") => Format::Text,
+                Some("json") => Format::Json,
+                Some("csv") => Format::Csv,
+                Some("ppm") => Format::Ppm,
+                _ => return Err(String::from("--format expects text, json, csv or ppm")),
+            };
+        } else if args[i] == "--baseline" {
+            i += 1;
+            options.baseline = match args.get(i) {
+                Some(value) => Some(value.to_string()),
+                None => return Err(String::from("--baseline expects a path")),
+            };
+        } else if args[i] == "--threshold" {
+            i += 1;
+            options.threshold = match args.get(i).map(|value| value.parse::<f64>()) {
+                Some(Ok(threshold)) if threshold >= 0.0 => threshold,
+                _ => return Err(String::from("--threshold expects a non-negative percentage")),
+            };
+        } else if args[i] == "--fast" || args[i] == "--slow" {
+            let seconds = match args.get(i + 1).map(|value| value.parse::<f64>()) {
+                Some(Ok(seconds)) if seconds >= 0.0 => seconds,
+                _ => return Err(format!("{} expects a non-negative number of seconds", args[i])),
+            };
+
+            if args[i] == "--fast" {
+                fast = Some(seconds);
+            } else {
+                slow = Some(seconds);
             }
+
+            i += 1;
+        } else if args[i] == "--jobs" {
+            i += 1;
+            options.jobs = match args.get(i).map(|value| value.parse::<usize>()) {
+                Some(Ok(jobs)) if jobs >= 1 => jobs,
+                _ => return Err(String::from("--jobs expects a positive number")),
+            };
+        } else if days.is_none() {
+            days = Some(args[i].to_string());
+        } else {
+            return Err(format!("Unexpected argument: {}", args[i]));
         }
+
+        i += 1;
     }
 
-    println!("Executing...");
-    let mut timed_results = vec![];
-    for day in 1..=DAYS {
-        let binary = format!("target/release/day_{:0>2}", day);
+    // Days are only resolved once the year is known,
+    // since `--year` may come after them.
+    let puzzles = registry::puzzles(options.year).unwrap_or(&[]);
+    options.days = match days {
+        Some(value) => parse_days(puzzles, &value)?,
+        None if all_by_default => parse_days(puzzles, "all")?,
+        None => return Err(String::from("No days selected")),
+    };
+
+    // When only one of the two is given, the other keeps its default
+    // unless that would cross it, then it is derived at the same ratio.
+    (options.fast, options.slow) = match (fast, slow) {
+        (Some(fast), Some(slow)) if fast > slow => {
+            return Err(format!("--fast ({}) cannot be above --slow ({})", fast, slow));
+        },
+        (Some(fast), Some(slow)) => (fast, slow),
+        (Some(fast), None) => (fast, SLOW.max(fast * SLOW / FAST)),
+        (None, Some(slow)) => (FAST.min(slow * FAST / SLOW), slow),
+        (None, None) => (FAST, SLOW),
+    };
+
+    if options.input.is_some() && options.days.len() != 1 {
+        return Err(String::from("--input requires exactly one day"));
+    }
+
+    return Ok(options);
+}
+
+// `submit DAY PART` is shorthand
+// for `submit DAY --part PART`.
+fn parse_submit_options(args: &[String]) -> Result<Options, String> {
+    let mut args = args.to_vec();
+    if args.len() >= 2 && !args[1].starts_with("--") {
+        args.insert(1, String::from("--part"));
+    }
+
+    return parse_options(&args, false);
+}
+
+fn parse_day(puzzles: &[Puzzle], day: &str) -> Result<i64, String> {
+    return match day.parse::<i64>() {
+        Ok(value) if puzzles.iter().any(|puzzle| puzzle.day == value) => Ok(value),
+        Ok(value) => Err(format!("No solution for day {}", value)),
+        Err(_) => Err(format!("Invalid day: {}", day)),
+    };
+}
+
+fn parse_days(puzzles: &[Puzzle], days: &str) -> Result<Vec<i64>, String> {
+    if days == "all" {
+        return Ok(puzzles.iter().map(|puzzle| puzzle.day).collect());
+    }
+
+    let range: Vec<i64> = if let Some((first, last)) = days.split_once("..=") {
+        (parse_day(puzzles, first)?..=parse_day(puzzles, last)?).collect()
+    } else if let Some((first, last)) = days.split_once("..") {
+        (parse_day(puzzles, first)?..parse_day(puzzles, last)?).collect()
+    } else {
+        return Ok(vec![parse_day(puzzles, days)?]);
+    };
+
+    // Otherwise a reversed range would quietly do nothing.
+    if range.is_empty() {
+        return Err(format!("Empty day range: {}", days));
+    }
+
+    return Ok(range);
+}
+
+fn run(options: &Options) -> Result<(), String> {
+    if options.format != Format::Text && options.format != Format::Json {
+        return Err(String::from("run expects --format text or json"));
+    }
+
+    let mut failed = false;
+    let puzzles = options.puzzles();
+    for_each_in_parallel(&puzzles, options.jobs, |puzzle| run_puzzle(options, puzzle), |puzzle, result| {
+        match result {
+            Ok((lines, succeeded)) => {
+                for line in lines {
+                    println!("{}", line);
+                }
+
+                failed |= !succeeded;
+            },
+            Err(message) if options.format == Format::Json => {
+                let reason = format!("panicked: {}", message);
+                println!("{{ \"day\": {}, \"error\": {} }}", puzzle.day, json::escape(&reason));
+                failed = true;
+            },
+            Err(message) => {
+                println!("Day {:0>2}: {}", puzzle.day, format!("panicked: {}", message).red());
+                failed = true;
+            },
+        };
+    });
+
+    // The errors have already been printed
+    // with their day, only the status is left.
+    if failed {
+        process::exit(1);
+    }
+
+    return Ok(());
+}
 
+// The json format is one object per line, which is what `watch`
+// reads back from its child process. Also returns whether every
+// part was solved, so that `run` can exit with an error otherwise.
+fn run_puzzle(options: &Options, puzzle: &Puzzle) -> (Vec<String>, bool) {
+    let text = options.format == Format::Text;
+
+    let solution = match load_and_parse(puzzle, &options.input_for(puzzle)) {
+        Ok(value) => value,
+        Err(reason) if text => return (vec![format!("Day {:0>2}: {}", puzzle.day, reason.to_string().red())], false),
+        Err(reason) => return (vec![format!("{{ \"day\": {}, \"error\": {} }}", puzzle.day, json::escape(&reason.to_string()))], false),
+    };
+
+    let mut succeeded = true;
+
+    let mut lines = vec![format!("Day {:0>2}:", puzzle.day)];
+    let mut parts = vec![];
+    for part in &options.parts {
         let now = Instant::now();
+        let result = solution.solve(*part);
+        let seconds = now.elapsed().as_secs_f64();
+
+        match result {
+            Ok(value) => {
+                lines.push(format!("    Part {}: {}", part, value));
+                parts.push(format!("{{ \"part\": {}, \"answer\": {}, \"seconds\": {} }}", part, value.to_json(), seconds));
+            },
+            Err(reason) => {
+                succeeded = false;
+                lines.push(format!("    Part {}: {}", part, reason.to_string().red()));
+                parts.push(format!("{{ \"part\": {}, \"error\": {}, \"seconds\": {} }}", part, json::escape(&reason.to_string()), seconds));
+            },
+        };
+    }
+
+    if text {
+        return (lines, succeeded);
+    }
+
+    return (vec![format!("{{ \"day\": {}, \"parts\": [{}] }}", puzzle.day, parts.join(", "))], succeeded);
+}
+
+// Takes its own arguments, since the day does not
+// exist yet and would be rejected by `parse_days`.
+fn new(args: &[String]) -> Result<(), String> {
+    let (year, day) = match args {
+        [year, day] => (year.parse::<i64>(), day.parse::<i64>()),
+        _ => return Err(String::from("new expects a year and a day, e.g. `new 2022 1`")),
+    };
+
+    let (year, day) = match (year, day) {
+        (Ok(year), Ok(day)) if year >= 2015 && (1..=25).contains(&day) => (year, day),
+        _ => return Err(String::from("new expects a year from 2015 on and a day from 1 to 25")),
+    };
+
+    let created = match scaffold::create(Path::new("."), year, day) {
+        Ok(value) => value,
+        Err(reason) => return Err(reason.to_string()),
+    };
+
+    for file_name in created {
+        println!("{} {}", "wrote".green(), file_name);
+    }
+
+    return Ok(());
+}
+
+fn watch(options: &Options) -> Result<(), String> {
+    let puzzles = options.puzzles();
+    if puzzles.len() != 1 {
+        return Err(String::from("watch expects a single day, e.g. `watch 7`"));
+    }
+
+    let puzzle = puzzles[0];
+    let mut files = watch::watched_files(options.year, puzzle.day);
+    if let Some(input) = &options.input {
+        files.push(input.to_string());
+    }
+
+    let mut snapshot = Snapshot::take(&files);
+    let mut previous = watch_run(options, puzzle, &[]);
+    loop {
+        thread::sleep(watch::POLL_INTERVAL);
+
+        // Look for new inputs on every poll, so that
+        // adding an example file also triggers a run.
+        files = watch::watched_files(options.year, puzzle.day);
+        if let Some(input) = &options.input {
+            files.push(input.to_string());
+        }
+
+        let current = Snapshot::take(&files);
+        let changes = snapshot.changes(&current);
+        snapshot = current;
+
+        if !changes.is_empty() {
+            println!();
+            println!("Changed: {}", changes.join(", "));
+            previous = watch_run(options, puzzle, &previous);
+        }
+    }
+}
+
+// The day is rebuilt and run by a child process, since this
+// binary cannot pick up changes to its own code. Returns the
+// parts as (part, outcome, seconds) for the next comparison.
+fn watch_run(options: &Options, puzzle: &Puzzle, previous: &[(i64, String, f64)]) -> Vec<(i64, String, f64)> {
+    let mut command = Command::new("cargo");
+    command.args(["run", "--release", "--quiet", "--bin", "aoc", "--", "run", &puzzle.day.to_string(), "--format", "json"]);
+    command.args(["--year", &options.year.to_string()]);
+    if options.parts.len() == 1 {
+        command.args(["--part", &options.parts[0].to_string()]);
+    }
+
+    if let Some(input) = &options.input {
+        command.args(["--input", input]);
+    }
+
+    println!("Day {:0>2}: building and running...", puzzle.day);
+    let output = match command.stderr(Stdio::inherit()).output() {
+        Ok(value) => value,
+        Err(reason) => {
+            println!("    {}", format!("Failed to run cargo: {}", reason).red());
+            return previous.to_vec();
+        },
+    };
+
+    // `run` also fails when a part does, but then
+    // there is still the output of the other parts.
+    if !output.status.success() && output.stdout.is_empty() {
+        println!("    {}", "Build failed, waiting for the next change".red());
+        return previous.to_vec();
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parts = match parse_watch_output(&stdout) {
+        Ok(value) => value,
+        Err(reason) => {
+            println!("    {}", reason.red());
+            return previous.to_vec();
+        },
+    };
+
+    for (part, outcome, seconds) in &parts {
+        let mut line = format!("    Part {}: {} in {} seconds", part, outcome, colorize(*seconds, options));
+        match previous.iter().find(|(before, _, _)| before == part) {
+            Some((_, before, before_seconds)) if before == outcome => {
+                line = format!("{}  (same answer, was {:.8} seconds)", line, before_seconds);
+            },
+            Some((_, before, before_seconds)) => {
+                let was = format!("(was {} in {:.8} seconds)", before, before_seconds).yellow();
+                line = format!("{}  {}", line, was);
+            },
+            None => {},
+        };
+
+        println!("{}", line);
+    }
+
+    return parts;
+}
+
+fn parse_watch_output(stdout: &str) -> Result<Vec<(i64, String, f64)>, String> {
+    let root = match json::parse(stdout.trim()) {
+        Ok(value) => value,
+        Err(reason) => return Err(format!("Unexpected output from `aoc run`: {}", reason)),
+    };
+
+    if let Some(reason) = root.get("error").and_then(Json::as_str) {
+        return Err(reason.to_string());
+    }
+
+    let mut parts = vec![];
+    for part in root.get("parts").and_then(Json::as_array).unwrap_or(&[]) {
+        let number = part.get("part").and_then(Json::as_f64).unwrap_or(0.0) as i64;
+        let seconds = part.get("seconds").and_then(Json::as_f64).unwrap_or(0.0);
+
+        // Answers are numbers or strings, see `Answer::to_json`.
+        let outcome = match (part.get("answer"), part.get("error").and_then(Json::as_str)) {
+            (Some(Json::Number(answer)), _) => answer.to_string(),
+            (Some(Json::String(answer)), _) => answer.to_string(),
+            (Some(_), _) => String::from("error: unexpected answer"),
+            (None, Some(reason)) => format!("error: {}", reason),
+            (None, None) => String::from("error: no answer"),
+        };
+
+        parts.push((number, outcome, seconds));
+    }
+
+    return Ok(parts);
+}
+
+// Solves the puzzles on `jobs` threads, but reports them in day
+// order: a result is held back until every earlier day is done.
+// A panicking day is reported with its message, the others go on.
+fn for_each_in_parallel<T, S, R>(puzzles: &[&'static Puzzle], jobs: usize, solve: S, mut report: R)
+where
+    T: Send,
+    S: Fn(&Puzzle) -> T + Sync,
+    R: FnMut(&Puzzle, Result<T, String>),
+{
+    // The default hook would print each panic to stderr
+    // right away, out of order with the other days.
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
 
-        let mut execute_cmd = Command::new(binary);
-        execute_cmd.stdout(Stdio::null());
-        execute_cmd.stderr(Stdio::null());
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
 
-        if let Ok(mut execute_process) = execute_cmd.spawn() {
-            if let Ok(execute_status) = execute_process.wait() {
-                if !execute_status.success() {
-                    if let Some(execute_code) = execute_status.code() {
-                        panic!("Execute process failed: Day {:0>2} (exit status: {})", day, execute_code);
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(puzzles.len()) {
+            let sender = sender.clone();
+            let (next, solve) = (&next, &solve);
+
+            scope.spawn(move || {
+                loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    if index >= puzzles.len() {
+                        break;
+                    }
+
+                    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| solve(puzzles[index])));
+                    if sender.send((index, result.map_err(panic_message))).is_err() {
+                        break;
                     }
                 }
+            });
+        }
+
+        drop(sender);
+
+        let mut results: Vec<Option<Result<T, String>>> = puzzles.iter().map(|_| None).collect();
+        let mut reported = 0;
+        for (index, result) in receiver {
+            results[index] = Some(result);
+
+            while reported < puzzles.len() {
+                match results[reported].take() {
+                    Some(result) => report(puzzles[reported], result),
+                    None => break,
+                };
+
+                reported += 1;
             }
         }
+    });
+
+    panic::set_hook(default_hook);
+}
+
+fn panic_message(payload: Box<dyn std::any::Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        return message.to_string();
+    }
+
+    if let Some(message) = payload.downcast_ref::<String>() {
+        return message.clone();
+    }
+
+    return String::from("unknown reason");
+}
+
+fn fetch(options: &Options) -> Result<(), String> {
+    let session = match fetch::session() {
+        Some(value) => value,
+        None => return Err(format!("No session token, set AOC_SESSION or save it to {}", fetch::SESSION_FILE)),
+    };
+
+    let fetcher = Fetcher::new(&session);
+    for puzzle in options.puzzles() {
+        let file_name = options.input_for(puzzle);
+        match fetcher.fetch(options.year, puzzle.day, &file_name) {
+            Ok(_) => println!("Day {:0>2}: saved {}", puzzle.day, file_name),
+            Err(reason) => println!("Day {:0>2}: {}", puzzle.day, reason.to_string().red()),
+        };
+    }
+
+    return Ok(());
+}
+
+fn submit(options: &Options) -> Result<(), String> {
+    let puzzles = options.puzzles();
+    if puzzles.len() != 1 || options.parts.len() != 1 {
+        return Err(String::from("submit expects a single day and part, e.g. `submit 7 2`"));
+    }
+
+    let (puzzle, part) = (puzzles[0], options.parts[0]);
+    let answer = match load_and_parse(puzzle, &options.input_for(puzzle)).and_then(|solution| solution.solve(part)) {
+        Ok(value) => value,
+        Err(reason) => return Err(format!("Day {:0>2}: {}", puzzle.day, reason)),
+    };
 
-        let duration = now.elapsed();
+    // Day 13 draws its answer, which has to be read by a person.
+    if answer.is_multiline() {
+        return Err(format!("Day {:0>2} part {} has to be read and submitted by hand:{}", puzzle.day, part, answer));
+    }
+
+    let answer = answer.to_string();
+
+    let mut history = match History::load(HISTORY) {
+        Ok(value) => value,
+        Err(reason) => return Err(format!("{}: {}", HISTORY, reason)),
+    };
+
+    if let Some(reason) = history.refusal(options.year, puzzle.day, part, &answer) {
+        return Err(format!("Not submitting {}: {}", answer, reason));
+    }
+
+    let session = match fetch::session() {
+        Some(value) => value,
+        None => return Err(format!("No session token, set AOC_SESSION or save it to {}", fetch::SESSION_FILE)),
+    };
+
+    let fetcher = Fetcher::new(&session);
+    let outcome = match submit::submit(&fetcher, &mut history, options.year, puzzle.day, part, &answer) {
+        Ok(value) => value,
+        Err(reason) => return Err(reason.to_string()),
+    };
 
-        let day = format!("Day {:0>2}", day);
+    let summary = format!("Day {:0>2} part {}: {} was {}", puzzle.day, part, answer, outcome);
+    match outcome {
+        Outcome::Correct => println!("{}", summary.green()),
+        Outcome::RateLimited | Outcome::Unknown => println!("{}", summary.yellow()),
+        _ => println!("{}", summary.red()),
+    };
 
-        timed_results.push(TimedResult::new(&day, duration));
+    return Ok(());
+}
+
+fn visualize(options: &Options) -> Result<(), String> {
+    let puzzles = options.puzzles();
+    if puzzles.len() != 1 {
+        return Err(String::from("visualize expects a single day, e.g. `visualize 15`"));
+    }
+
+    let format = match options.format {
+        Format::Text => Visualization::Terminal,
+        Format::Ppm if options.parts.len() == 1 => Visualization::Ppm,
+        Format::Ppm => return Err(String::from("--format ppm draws a single image, pick one with --part")),
+        _ => return Err(String::from("visualize expects --format text or ppm")),
+    };
+
+    let puzzle = puzzles[0];
+    let solution = match load_and_parse(puzzle, &options.input_for(puzzle)) {
+        Ok(value) => value,
+        Err(reason) => return Err(format!("Day {:0>2}: {}", puzzle.day, reason)),
+    };
+
+    for part in &options.parts {
+        match solution.visualize(*part, format) {
+            Ok(Some(value)) => print!("{}", value),
+            Ok(None) => return Err(format!("Day {:0>2} has no visualization", puzzle.day)),
+            Err(reason) => return Err(format!("Day {:0>2}: {}", puzzle.day, reason)),
+        };
     }
 
-    for timed_result in &timed_results {
-        let mut color = Color::Red;
-        if timed_result.duration < 0.5 {
-            color = Color::Green;
-        } else if timed_result.duration < 1.0 {
-            color = Color::Yellow;
+    return Ok(());
+}
+
+fn verify(options: &Options) -> Result<(), String> {
+    if options.input.is_some() {
+        return Err(String::from("verify always uses the recorded inputs"));
+    }
+
+    let file_name = options.answers_file();
+    let answers = match Answers::load(&file_name) {
+        Ok(value) => value,
+        Err(reason) => return Err(format!("{}: {}", file_name, reason)),
+    };
+
+    // The days are solved in parallel, but compared
+    // and printed in day order as they are reported.
+    let solve = |puzzle: &Puzzle| -> Result<Vec<Result<Answer, AocError>>, AocError> {
+        let solution = load_and_parse(puzzle, puzzle.input)?;
+        return Ok(options.parts.iter().map(|part| solution.solve(*part)).collect());
+    };
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    let puzzles = options.puzzles();
+    for_each_in_parallel(&puzzles, options.jobs, solve, |puzzle, result| {
+        let results = match result {
+            Ok(Ok(value)) => value,
+            Ok(Err(reason)) => {
+                println!("Day {:0>2}: {}", puzzle.day, reason.to_string().red());
+                failed += options.parts.len();
+                return;
+            },
+            Err(message) => {
+                println!("Day {:0>2}: {}", puzzle.day, format!("panicked: {}", message).red());
+                failed += options.parts.len();
+                return;
+            },
+        };
+
+        println!("Day {:0>2}:", puzzle.day);
+        for (part, result) in options.parts.iter().zip(results) {
+            let actual = match result {
+                Ok(value) => value,
+                Err(reason) => {
+                    println!("    Part {}: {}", part, reason.to_string().red());
+                    failed += 1;
+                    continue;
+                },
+            };
+
+            match answers.get(puzzle.day, *part) {
+                Some(expected) if *expected == actual => {
+                    println!("    Part {}: {}", part, "pass".green());
+                    passed += 1;
+                },
+                Some(expected) => {
+                    println!("    Part {}: {}", part, "FAIL".red());
+                    print_diff(&expected.to_string(), &actual.to_string());
+                    failed += 1;
+                },
+                None => {
+                    println!("    Part {}: {}", part, "missing".yellow());
+                    missing += 1;
+                },
+            };
         }
+    });
+
+    println!();
+    println!("{} passed, {} failed, {} missing", passed, failed, missing);
+    if failed > 0 {
+        process::exit(1);
+    }
 
-        let duration = timed_result.duration.to_string();
+    return Ok(());
+}
 
-        println!("    {}: {:.8} seconds", timed_result.day, duration.color(color));
+// Multi-line answers (day 13) are compared line by
+// line so that only the differing rows are flagged.
+fn print_diff(expected: &str, actual: &str) {
+    for pair in expected.lines().zip_longest(actual.lines()) {
+        match pair {
+            EitherOrBoth::Both(left, right) if left == right => {
+                println!("          {}", left);
+            },
+            EitherOrBoth::Both(left, right) => {
+                println!("        - {}", left.red());
+                println!("        + {}", right.green());
+            },
+            EitherOrBoth::Left(left) => println!("        - {}", left.red()),
+            EitherOrBoth::Right(right) => println!("        + {}", right.green()),
+        };
     }
 }
+
+fn bench(options: &Options) -> Result<(), String> {
+    // Days running side by side would slow each other down.
+    if options.jobs > 1 {
+        return Err(String::from("bench always runs one day at a time, --jobs is for `run`"));
+    }
+
+    if options.format == Format::Ppm {
+        return Err(String::from("bench expects --format text, json or csv"));
+    }
+
+    // Read the baseline before benchmarking so that
+    // a bad path does not waste a full run.
+    let baseline = match &options.baseline {
+        Some(path) => match Baseline::load(path) {
+            Ok(value) => Some(value),
+            Err(reason) => return Err(format!("{}: {}", path, reason)),
+        },
+        None => None,
+    };
+
+    // Only the results go to stdout for json and csv,
+    // so that they can be redirected into a file.
+    let text = options.format == Format::Text;
+    if text {
+        println!("Executing...");
+    }
+
+    let mut timed_results = vec![];
+    for puzzle in options.puzzles() {
+        // Load the input once up front so the
+        // timings below do not include file I/O.
+        let input = match aoc::load(&options.input_for(puzzle)) {
+            Ok(value) => value,
+            Err(reason) => {
+                print_failure(text, puzzle.day, &reason);
+                continue;
+            },
+        };
+
+        match bench::benchmark(puzzle, &input, &options.parts) {
+            Ok(value) => timed_results.push(value),
+            Err(reason) => print_failure(text, puzzle.day, &reason),
+        }
+    }
+
+    let revision = bench::git_revision();
+    match options.format {
+        Format::Text | Format::Ppm => print_timings(&timed_results, baseline.as_ref(), options),
+        Format::Json => print!("{}", bench::to_json(&revision, &timed_results)),
+        Format::Csv => print!("{}", bench::to_csv(&revision, &timed_results)),
+    };
+
+    if let Some(baseline) = &baseline {
+        let regressions = bench::find_regressions(&timed_results, baseline, options.threshold);
+        for regression in &regressions {
+            eprintln!("{}: Day {:0>2} {} median {:.8} -> {:.8} seconds ({:+.1}%)", "regression".red(),
+                regression.day, regression.phase, regression.baseline, regression.current,
+                bench::percent_change(regression.baseline, regression.current));
+        }
+
+        if !regressions.is_empty() {
+            eprintln!("{} regressions above {}% compared to {}",
+                regressions.len(), options.threshold, baseline.revision);
+            process::exit(1);
+        }
+    }
+
+    return Ok(());
+}
+
+fn print_failure(text: bool, day: i64, reason: &AocError) {
+    let failure = format!("    Day {:0>2}: {}", day, reason.to_string().red());
+    if text {
+        println!("{}", failure);
+    } else {
+        eprintln!("{}", failure);
+    }
+}
+
+fn print_timings(timed_results: &[TimedResult], baseline: Option<&Baseline>, options: &Options) {
+    let threshold = options.threshold;
+    for timed_result in timed_results {
+        println!("    Day {:0>2}:", timed_result.day);
+        for phase in &timed_result.phases {
+            print!("        {:<6}  min {}  median {}  max {} seconds", phase.name,
+                colorize(phase.min(), options), colorize(phase.median(), options), colorize(phase.max(), options));
+
+            if let Some(median) = baseline.and_then(|value| value.median(timed_result.day, &phase.name)) {
+                let change = bench::percent_change(median, phase.median());
+                let mut summary = format!("({:+.1}% vs {:.8})", change, median).normal();
+                if change > threshold {
+                    summary = summary.red();
+                } else if change < -threshold {
+                    summary = summary.green();
+                }
+
+                print!("  {}", summary);
+            }
+
+            println!();
+        }
+
+        for (name, duration) in &timed_result.report.spans {
+            println!("        {:<6}  {} {} seconds", "Span", name, colorize(duration.as_secs_f64(), options));
+        }
+
+        for (name, value) in &timed_result.report.counters {
+            println!("        {:<6}  {} {}", "Count", name, value);
+        }
+
+        if timed_result.peak_memory > 0 {
+            println!("        {:<6}  peak {}", "Memory", format_bytes(timed_result.peak_memory));
+        }
+    }
+}
+
+fn format_bytes(bytes: usize) -> String {
+    let units = ["bytes", "KiB", "MiB", "GiB"];
+
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < units.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        return format!("{} {}", bytes, units[0]);
+    }
+
+    return format!("{:.1} {}", value, units[unit]);
+}
+
+fn load_and_parse(puzzle: &Puzzle, file_name: &str) -> Result<Box<dyn aoc::Runnable>, AocError> {
+    let mut reader = aoc::open(file_name)?;
+    return (puzzle.parse)(&mut reader);
+}
+
+fn colorize(duration: f64, options: &Options) -> ColoredString {
+    let mut color = Color::Red;
+    if duration < options.fast {
+        color = Color::Green;
+    } else if duration < options.slow {
+        color = Color::Yellow;
+    }
+
+    return format!("{:.8}", duration).color(color);
+}
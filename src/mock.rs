@@ -0,0 +1,55 @@
+use std::io::Read;
+use std::io::Write;
+use std::net::TcpListener;
+use std::thread;
+use std::thread::JoinHandle;
+
+// Stands in for adventofcode.com: answers each connection with
+// the next canned response and hands back the raw requests.
+pub fn serve(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<String>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+
+    let handle = thread::spawn(move || {
+        let mut requests = vec![];
+        for (status, body) in responses {
+            let (mut stream, _) = listener.accept().unwrap();
+
+            let mut request = vec![];
+            let mut buffer = [0; 1024];
+            while !is_complete(&request) {
+                let count = stream.read(&mut buffer).unwrap();
+                if count == 0 { break; }
+                request.extend_from_slice(&buffer[..count]);
+            }
+
+            requests.push(String::from_utf8_lossy(&request).to_string());
+            write!(stream, "HTTP/1.1 {} Canned\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status, body.len(), body).unwrap();
+        }
+
+        return requests;
+    });
+
+    return (url, handle);
+}
+
+// A request is complete once the headers and
+// `Content-Length` bytes of body have arrived.
+fn is_complete(request: &[u8]) -> bool {
+    let text = String::from_utf8_lossy(request);
+    let (headers, body) = match text.split_once("\r\n\r\n") {
+        Some(value) => value,
+        None => return false,
+    };
+
+    for header in headers.lines() {
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                return body.len() >= value.trim().parse::<usize>().unwrap_or(0);
+            }
+        }
+    }
+
+    return true;
+}
@@ -0,0 +1,36 @@
+use crate::Puzzle;
+
+// The year that commands use when no `--year` is given.
+pub const DEFAULT_YEAR: i64 = 2021;
+
+// Rewritten by `aoc new` when it creates the first day of
+// a year, so keep one year per line in the same format.
+pub const YEARS: [(i64, &[Puzzle]); 1] = [
+    (2021, &crate::days::PUZZLES),
+];
+
+pub fn years() -> Vec<i64> {
+    return YEARS.iter().map(|(year, _)| *year).collect();
+}
+
+pub fn puzzles(year: i64) -> Option<&'static [Puzzle]> {
+    return YEARS.iter().find(|(value, _)| *value == year).map(|(_, puzzles)| *puzzles);
+}
+
+pub fn puzzle(year: i64, day: i64) -> Option<&'static Puzzle> {
+    return puzzles(year).and_then(|puzzles| puzzles.iter().find(|puzzle| puzzle.day == day));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lookup_by_year_and_day() {
+        assert_eq!(years(), [2021]);
+        assert_eq!(puzzles(2021).map(|puzzles| puzzles.len()), Some(25));
+        assert_eq!(puzzle(2021, 7).map(|puzzle| puzzle.input), Some("inputs/2021/day_07.txt"));
+        assert!(puzzle(2021, 26).is_none());
+        assert!(puzzles(2015).is_none());
+    }
+}
@@ -0,0 +1,381 @@
+use crate as aoc;
+use crate::fetch;
+use crate::AocError;
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+// Where the files of one year live. The 2021 days predate
+// support for other years and keep their module and binaries.
+pub struct Layout {
+    pub year: i64,
+    pub module: String,
+    pub inputs: String,
+    pub docs: String,
+    pub bin_prefix: String,
+}
+
+impl Layout {
+    pub fn for_year(year: i64) -> Layout {
+        if year == 2021 {
+            return Layout {
+                year: year,
+                module: String::from("days"),
+                inputs: String::from("inputs/2021"),
+                docs: String::from("docs/2021"),
+                bin_prefix: String::new(),
+            };
+        }
+
+        return Layout {
+            year: year,
+            module: format!("y{}", year),
+            inputs: format!("inputs/{}", year),
+            docs: format!("docs/{}", year),
+            bin_prefix: format!("y{}_", year),
+        };
+    }
+
+    pub fn source(&self, day: i64) -> String {
+        return format!("src/{}/day_{:0>2}.rs", self.module, day);
+    }
+
+    pub fn input(&self, day: i64) -> String {
+        return format!("{}/day_{:0>2}.txt", self.inputs, day);
+    }
+
+    pub fn doc(&self, day: i64) -> String {
+        return format!("{}/day_{:0>2}.md", self.docs, day);
+    }
+
+    pub fn bin(&self, day: i64) -> String {
+        return format!("src/bin/{}day_{:0>2}.rs", self.bin_prefix, day);
+    }
+}
+
+pub fn solution_template(day: i64) -> String {
+    return SOLUTION_TEMPLATE.replace("DayXY", &format!("Day{:0>2}", day));
+}
+
+const SOLUTION_TEMPLATE: &str = "\
+use crate::AocError;
+use crate::Solution;
+
+pub struct DayXY {
+}
+
+impl Solution for DayXY {
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(_input: &str) -> Result<DayXY, AocError> {
+        let day = DayXY {
+        };
+
+        return Ok(day);
+    }
+
+    fn part_1(&self) -> Result<i64, AocError> {
+        return solve_part_1(self);
+    }
+
+    fn part_2(&self) -> Result<i64, AocError> {
+        return solve_part_2(self);
+    }
+}
+
+fn solve_part_1(_day: &DayXY) -> Result<i64, AocError> {
+    return Err(AocError::NoSolution);
+}
+
+fn solve_part_2(_day: &DayXY) -> Result<i64, AocError> {
+    return Err(AocError::NoSolution);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = \"\\
+\";
+
+    #[test]
+    #[ignore = \"no example yet\"]
+    fn part_1_example() {
+        let day = DayXY::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part_1().unwrap(), 0);
+    }
+
+    #[test]
+    #[ignore = \"no example yet\"]
+    fn part_2_example() {
+        let day = DayXY::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part_2().unwrap(), 0);
+    }
+}
+";
+
+pub fn bin_template(layout: &Layout, day: i64) -> String {
+    let name = format!("day_{:0>2}", day);
+    return format!("use aoc::{}::{}::Day{:0>2};\n\nfn main() {{\n    aoc::run::<Day{:0>2}>(\"{}\");\n}}\n",
+        layout.module, name, day, day, layout.input(day));
+}
+
+pub fn doc_template(year: i64, day: i64) -> String {
+    return format!("<strong>--- Day {}: ---</strong>\n\n{}/{}/day/{}\n", day, fetch::BASE_URL, year, day);
+}
+
+// The registry of a year is generated from the days
+// present, so adding a day never needs a hand edit.
+pub fn module_template(layout: &Layout, days: &[i64]) -> String {
+    let mut module = String::from("use crate::Puzzle;\n\n");
+    for day in days {
+        module.push_str(&format!("pub mod day_{:0>2};\n", day));
+    }
+
+    module.push('\n');
+    for day in days {
+        module.push_str(&format!("use day_{:0>2}::Day{:0>2};\n", day, day));
+    }
+
+    module.push_str(&format!("\npub const PUZZLES: [Puzzle; {}] = [\n", days.len()));
+    for day in days {
+        module.push_str(&format!("    Puzzle::new::<Day{:0>2}>({}, \"{}\"),\n", day, day, layout.input(*day)));
+    }
+
+    module.push_str("];\n");
+    return module;
+}
+
+// Creates every file of a new day below `root`, and returns
+// their names. Refuses to overwrite an existing solution.
+pub fn create(root: &Path, year: i64, day: i64) -> Result<Vec<String>, AocError> {
+    let layout = Layout::for_year(year);
+
+    let source = layout.source(day);
+    if root.join(&source).exists() {
+        let reason = io::Error::new(io::ErrorKind::AlreadyExists, "Day already exists");
+        return Err(AocError::io(&source, reason));
+    }
+
+    let mut created = vec![];
+    let files = [
+        (source, solution_template(day)),
+        (layout.bin(day), bin_template(&layout, day)),
+        (layout.input(day), String::new()),
+        (layout.doc(day), doc_template(year, day)),
+    ];
+
+    for (file_name, contents) in files {
+        // Keep inputs and docs that were already fetched or written.
+        if root.join(&file_name).exists() {
+            continue;
+        }
+
+        write(root, &file_name, &contents)?;
+        created.push(file_name);
+    }
+
+    let module = format!("src/{}/mod.rs", layout.module);
+    write(root, &module, &module_template(&layout, &existing_days(root, &layout)?))?;
+    created.push(module);
+
+    if register_module(root, &layout.module)? {
+        created.push(String::from("src/lib.rs"));
+    }
+
+    if register_year(root)? {
+        created.push(String::from("src/registry.rs"));
+    }
+
+    return Ok(created);
+}
+
+pub fn years_template(years: &[i64]) -> String {
+    let mut table = format!("pub const YEARS: [(i64, &[Puzzle]); {}] = [\n", years.len());
+    for year in years {
+        table.push_str(&format!("    ({}, &crate::{}::PUZZLES),\n", year, Layout::for_year(*year).module));
+    }
+
+    table.push_str("];\n");
+    return table;
+}
+
+// Every directory below src with a `mod.rs` that
+// follows the layout of its year is one year.
+fn existing_years(root: &Path) -> Result<Vec<i64>, AocError> {
+    let entries = match fs::read_dir(root.join("src")) {
+        Ok(value) => value,
+        Err(reason) => return Err(AocError::io("src", reason)),
+    };
+
+    let mut years = vec![];
+    for entry in entries.flatten() {
+        let name = entry.file_name().to_string_lossy().to_string();
+        let year = match name.as_str() {
+            "days" => Some(2021),
+            _ => name.strip_prefix('y').and_then(|rest| rest.parse::<i64>().ok()),
+        };
+
+        if let Some(year) = year {
+            if Layout::for_year(year).module == name && entry.path().join("mod.rs").exists() {
+                years.push(year);
+            }
+        }
+    }
+
+    years.sort();
+    return Ok(years);
+}
+
+// Rewrites the `YEARS` table in registry.rs from
+// the years present. Returns false if unchanged.
+fn register_year(root: &Path) -> Result<bool, AocError> {
+    let file_name = "src/registry.rs";
+    let registry = aoc::load(&root.join(file_name).to_string_lossy())?;
+
+    let start = registry.find("pub const YEARS: [");
+    let end = start.and_then(|start| registry[start..].find("];\n").map(|end| start + end + 3));
+    let (start, end) = match (start, end) {
+        (Some(start), Some(end)) => (start, end),
+        _ => return Err(AocError::parse(1, 1, "No `YEARS` table found in src/registry.rs")),
+    };
+
+    let table = years_template(&existing_years(root)?);
+    if registry[start..end] == table {
+        return Ok(false);
+    }
+
+    write(root, file_name, &format!("{}{}{}", &registry[..start], table, &registry[end..]))?;
+    return Ok(true);
+}
+
+fn existing_days(root: &Path, layout: &Layout) -> Result<Vec<i64>, AocError> {
+    let directory = format!("src/{}", layout.module);
+    let entries = match fs::read_dir(root.join(&directory)) {
+        Ok(value) => value,
+        Err(reason) => return Err(AocError::io(&directory, reason)),
+    };
+
+    let mut days = vec![];
+    for entry in entries.flatten() {
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        let day = file_name.strip_prefix("day_").and_then(|rest| rest.strip_suffix(".rs"));
+        if let Some(Ok(day)) = day.map(|value| value.parse::<i64>()) {
+            days.push(day);
+        }
+    }
+
+    days.sort();
+    return Ok(days);
+}
+
+// Adds `pub mod <module>;` to lib.rs, keeping the module
+// declarations sorted. Returns false if already present.
+fn register_module(root: &Path, module: &str) -> Result<bool, AocError> {
+    let file_name = "src/lib.rs";
+    let lib = aoc::load(&root.join(file_name).to_string_lossy())?;
+
+    let declaration = format!("pub mod {};", module);
+    let mut lines: Vec<&str> = lib.lines().collect();
+    if lines.contains(&declaration.as_str()) {
+        return Ok(false);
+    }
+
+    let mut position = None;
+    for (i, line) in lines.iter().enumerate() {
+        if line.starts_with("pub mod ") {
+            if *line < declaration.as_str() {
+                position = Some(i + 1);
+            } else if position.is_none() {
+                position = Some(i);
+            }
+        }
+    }
+
+    match position {
+        Some(i) => lines.insert(i, &declaration),
+        None => lines.push(&declaration),
+    };
+
+    write(root, file_name, &(lines.join("\n") + "\n"))?;
+    return Ok(true);
+}
+
+fn write(root: &Path, file_name: &str, contents: &str) -> Result<(), AocError> {
+    let path = root.join(file_name);
+    if let Some(parent) = path.parent() {
+        if let Err(reason) = fs::create_dir_all(parent) {
+            return Err(AocError::io(file_name, reason));
+        }
+    }
+
+    return match fs::write(&path, contents) {
+        Ok(_) => Ok(()),
+        Err(reason) => Err(AocError::io(file_name, reason)),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::env;
+    use std::path::PathBuf;
+    use std::process;
+
+    fn scratch(name: &str) -> PathBuf {
+        let directory = env::temp_dir().join(format!("aoc-scaffold-{}-{}", process::id(), name));
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(directory.join("src")).unwrap();
+        fs::write(directory.join("src/lib.rs"), "pub mod answers;\npub mod watch;\n\npub use error::AocError;\n").unwrap();
+        fs::write(directory.join("src/registry.rs"), format!("use crate::Puzzle;\n\n{}", years_template(&[]))).unwrap();
+        return directory;
+    }
+
+    #[test]
+    fn module_template_matches_2021() {
+        let days: Vec<i64> = (1..=25).collect();
+        assert_eq!(module_template(&Layout::for_year(2021), &days), include_str!("days/mod.rs"));
+    }
+
+    #[test]
+    fn years_template_matches_registry() {
+        assert!(include_str!("registry.rs").contains(&years_template(&[2021])));
+    }
+
+    #[test]
+    fn create_new_year() {
+        let root = scratch("new-year");
+
+        let created = create(&root, 2022, 7).unwrap();
+        assert_eq!(created, [
+            "src/y2022/day_07.rs", "src/bin/y2022_day_07.rs", "inputs/2022/day_07.txt",
+            "docs/2022/day_07.md", "src/y2022/mod.rs", "src/lib.rs", "src/registry.rs",
+        ]);
+
+        create(&root, 2022, 3).unwrap();
+
+        let module = fs::read_to_string(root.join("src/y2022/mod.rs")).unwrap();
+        assert!(module.contains("pub const PUZZLES: [Puzzle; 2] = [\n    Puzzle::new::<Day03>(3, \"inputs/2022/day_03.txt\"),\n"));
+
+        let lib = fs::read_to_string(root.join("src/lib.rs")).unwrap();
+        assert_eq!(lib, "pub mod answers;\npub mod watch;\npub mod y2022;\n\npub use error::AocError;\n");
+
+        let registry = fs::read_to_string(root.join("src/registry.rs")).unwrap();
+        assert!(registry.ends_with("[(i64, &[Puzzle]); 1] = [\n    (2022, &crate::y2022::PUZZLES),\n];\n"));
+
+        let bin = fs::read_to_string(root.join("src/bin/y2022_day_07.rs")).unwrap();
+        assert!(bin.contains("aoc::run::<Day07>(\"inputs/2022/day_07.txt\");"));
+    }
+
+    #[test]
+    fn create_refuses_existing_day() {
+        let root = scratch("existing");
+        create(&root, 2022, 1).unwrap();
+
+        let error = create(&root, 2022, 1).unwrap_err();
+        assert!(error.to_string().ends_with("Day already exists"));
+    }
+}
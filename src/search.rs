@@ -0,0 +1,237 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::hash::Hash;
+
+// A puzzle describes its state space through this trait,
+// the searches below only ever look at states through it.
+pub trait SearchSpace {
+    type State: Clone + Eq + Hash;
+
+    // Every state reachable in one move, with the cost of that move.
+    fn neighbors(&self, state: &Self::State) -> Vec<(Self::State, i64)>;
+
+    fn is_goal(&self, state: &Self::State) -> bool;
+
+    // Must never overestimate the remaining cost,
+    // otherwise A* can return a more expensive path.
+    fn heuristic(&self, _state: &Self::State) -> i64 {
+        return 0;
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Path<S> {
+    pub cost: i64,
+    pub states: Vec<S>,
+}
+
+// Ignores the move costs, so the path found has the fewest
+// moves and its cost is the number of moves taken.
+pub fn bfs<P: SearchSpace>(space: &P, start: P::State) -> Option<Path<P::State>> {
+    let mut previous = HashMap::new();
+    let mut seen = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(state) = queue.pop_front() {
+        if space.is_goal(&state) {
+            let states = reconstruct(&previous, state);
+            return Some(Path {
+                cost: (states.len() - 1) as i64,
+                states: states,
+            });
+        }
+
+        for (neighbor, _) in space.neighbors(&state) {
+            if seen.insert(neighbor.clone()) {
+                previous.insert(neighbor.clone(), state.clone());
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    return None;
+}
+
+// Every state reachable from the start, including the start
+// itself. The goal test is not used, this is a flood fill.
+pub fn reachable<P: SearchSpace>(space: &P, start: P::State) -> HashSet<P::State> {
+    let mut seen = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(state) = queue.pop_front() {
+        for (neighbor, _) in space.neighbors(&state) {
+            if seen.insert(neighbor.clone()) {
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    return seen;
+}
+
+pub fn dijkstra<P: SearchSpace>(space: &P, start: P::State) -> Option<Path<P::State>> {
+    return best_first(space, start, false);
+}
+
+pub fn a_star<P: SearchSpace>(space: &P, start: P::State) -> Option<Path<P::State>> {
+    return best_first(space, start, true);
+}
+
+struct Entry<S> {
+    priority: i64,
+    cost: i64,
+    state: S,
+}
+
+// Reversed, so the BinaryHeap (a max-heap)
+// pops the lowest priority first.
+impl<S> Ord for Entry<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        return other.priority.cmp(&self.priority);
+    }
+}
+
+impl<S> PartialOrd for Entry<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+impl<S> PartialEq for Entry<S> {
+    fn eq(&self, other: &Self) -> bool {
+        return self.priority == other.priority;
+    }
+}
+
+impl<S> Eq for Entry<S> {}
+
+fn best_first<P: SearchSpace>(space: &P, start: P::State, use_heuristic: bool) -> Option<Path<P::State>> {
+    let heuristic = |state: &P::State| {
+        if use_heuristic {
+            return space.heuristic(state);
+        }
+
+        return 0;
+    };
+
+    let mut costs = HashMap::from([(start.clone(), 0)]);
+    let mut previous = HashMap::new();
+    let mut queue = BinaryHeap::from([Entry {
+        priority: heuristic(&start),
+        cost: 0,
+        state: start,
+    }]);
+
+    while let Some(entry) = queue.pop() {
+        // A state is pushed again whenever a cheaper way to
+        // it is found, so skip the more expensive leftovers.
+        if costs.get(&entry.state).is_some_and(|cost| *cost < entry.cost) {
+            continue;
+        }
+
+        if space.is_goal(&entry.state) {
+            return Some(Path {
+                cost: entry.cost,
+                states: reconstruct(&previous, entry.state),
+            });
+        }
+
+        for (neighbor, step) in space.neighbors(&entry.state) {
+            let cost = entry.cost + step;
+            if costs.get(&neighbor).is_some_and(|known| *known <= cost) {
+                continue;
+            }
+
+            costs.insert(neighbor.clone(), cost);
+            previous.insert(neighbor.clone(), entry.state.clone());
+            queue.push(Entry {
+                priority: cost + heuristic(&neighbor),
+                cost: cost,
+                state: neighbor,
+            });
+        }
+    }
+
+    return None;
+}
+
+fn reconstruct<S: Clone + Eq + Hash>(previous: &HashMap<S, S>, goal: S) -> Vec<S> {
+    let mut states = vec![goal];
+    while let Some(state) = previous.get(&states[states.len() - 1]) {
+        states.push(state.clone());
+    }
+
+    states.reverse();
+    return states;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 -> 1 -> 3 is the fewest moves, but
+    // 0 -> 2 -> 1 -> 3 is the cheapest path.
+    struct Graph {
+        edges: Vec<Vec<(usize, i64)>>,
+        goal: usize,
+    }
+
+    impl SearchSpace for Graph {
+        type State = usize;
+
+        fn neighbors(&self, state: &usize) -> Vec<(usize, i64)> {
+            return self.edges[*state].clone();
+        }
+
+        fn is_goal(&self, state: &usize) -> bool {
+            return *state == self.goal;
+        }
+
+        // Every edge costs at least 1.
+        fn heuristic(&self, state: &usize) -> i64 {
+            if *state == self.goal {
+                return 0;
+            }
+
+            return 1;
+        }
+    }
+
+    fn graph() -> Graph {
+        return Graph {
+            edges: vec![
+                vec![(1, 10), (2, 1)],
+                vec![(3, 1)],
+                vec![(1, 1), (4, 1)],
+                vec![],
+                vec![],
+            ],
+            goal: 3,
+        };
+    }
+
+    #[test]
+    fn bfs_takes_fewest_moves() {
+        assert_eq!(bfs(&graph(), 0), Some(Path { cost: 2, states: vec![0, 1, 3] }));
+    }
+
+    #[test]
+    fn dijkstra_and_a_star_take_cheapest_path() {
+        let expected = Some(Path { cost: 3, states: vec![0, 2, 1, 3] });
+        assert_eq!(dijkstra(&graph(), 0), expected);
+        assert_eq!(a_star(&graph(), 0), expected);
+    }
+
+    #[test]
+    fn unreachable_goal() {
+        let mut graph = graph();
+        graph.goal = 5;
+
+        assert_eq!(bfs(&graph, 0), None);
+        assert_eq!(dijkstra(&graph, 0), None);
+        assert_eq!(reachable(&graph, 2), HashSet::from([1, 2, 3, 4]));
+    }
+}
@@ -0,0 +1,321 @@
+use crate as aoc;
+use crate::fetch::Fetcher;
+use crate::registry;
+use crate::AocError;
+
+use std::fmt;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    RateLimited,
+    AlreadySolved,
+    Unknown,
+}
+
+impl Outcome {
+    fn name(&self) -> &'static str {
+        return match self {
+            Outcome::Correct => "correct",
+            Outcome::Incorrect => "incorrect",
+            Outcome::TooHigh => "too-high",
+            Outcome::TooLow => "too-low",
+            Outcome::RateLimited => "rate-limited",
+            Outcome::AlreadySolved => "already-solved",
+            Outcome::Unknown => "unknown",
+        };
+    }
+
+    fn from_name(name: &str) -> Option<Outcome> {
+        let outcomes = [
+            Outcome::Correct, Outcome::Incorrect, Outcome::TooHigh, Outcome::TooLow,
+            Outcome::RateLimited, Outcome::AlreadySolved, Outcome::Unknown,
+        ];
+
+        return outcomes.into_iter().find(|outcome| outcome.name() == name);
+    }
+
+    pub fn is_wrong(&self) -> bool {
+        return [Outcome::Incorrect, Outcome::TooHigh, Outcome::TooLow].contains(self);
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(f, "{}", self.name());
+    }
+}
+
+// The website answers with a full HTML page, but each
+// outcome has a recognizable sentence in its <article>.
+pub fn parse_response(html: &str) -> Outcome {
+    if html.contains("That's the right answer") {
+        return Outcome::Correct;
+    }
+
+    if html.contains("That's not the right answer") {
+        if html.contains("your answer is too high") {
+            return Outcome::TooHigh;
+        } else if html.contains("your answer is too low") {
+            return Outcome::TooLow;
+        }
+
+        return Outcome::Incorrect;
+    }
+
+    if html.contains("You gave an answer too recently") {
+        return Outcome::RateLimited;
+    }
+
+    if html.contains("You don't seem to be solving the right level") {
+        return Outcome::AlreadySolved;
+    }
+
+    return Outcome::Unknown;
+}
+
+pub struct Attempt {
+    pub timestamp: u64,
+    pub year: i64,
+    pub day: i64,
+    pub part: i64,
+    pub outcome: Outcome,
+    pub answer: String,
+}
+
+// One tab separated attempt per line: timestamp, year, day, part,
+// outcome, answer. Lines without a year are from the default year.
+pub struct History {
+    file_name: String,
+    pub attempts: Vec<Attempt>,
+}
+
+impl History {
+    pub fn load(file_name: &str) -> Result<History, AocError> {
+        let mut history = History {
+            file_name: file_name.to_string(),
+            attempts: vec![],
+        };
+
+        if !Path::new(file_name).exists() {
+            return Ok(history);
+        }
+
+        for (number, line) in aoc::numbered_lines(&aoc::load(file_name)?) {
+            let mut fields: Vec<&str> = line.splitn(6, '\t').collect();
+            if fields.len() == 5 {
+                fields.insert(1, "");
+            } else if fields.len() != 6 {
+                return Err(aoc::missing_token(number, line, "Expected 6 tab separated fields"));
+            }
+
+            history.attempts.push(Attempt {
+                timestamp: aoc::parse_token(number, line, fields[0])?,
+                year: match fields[1] {
+                    "" => registry::DEFAULT_YEAR,
+                    value => aoc::parse_token(number, line, value)?,
+                },
+                day: aoc::parse_token(number, line, fields[2])?,
+                part: aoc::parse_token(number, line, fields[3])?,
+                outcome: match Outcome::from_name(fields[4]) {
+                    Some(value) => value,
+                    None => {
+                        let message = format!("Unknown outcome: {}", fields[4]);
+                        return Err(AocError::parse(number, aoc::column_of(line, fields[4]), &message));
+                    },
+                },
+                answer: fields[5].to_string(),
+            });
+        }
+
+        return Ok(history);
+    }
+
+    // Besides exact repeats, a numeric answer is also known to be
+    // wrong once it is beyond an answer that was too high or too low.
+    pub fn refusal(&self, year: i64, day: i64, part: i64, answer: &str) -> Option<String> {
+        let number = answer.parse::<i64>().ok();
+
+        for attempt in &self.attempts {
+            if attempt.year != year || attempt.day != day || attempt.part != part {
+                continue;
+            }
+
+            if attempt.outcome == Outcome::Correct {
+                return Some(format!("Already solved with {}", attempt.answer));
+            } else if attempt.outcome == Outcome::AlreadySolved {
+                return Some(String::from("Already solved according to the website"));
+            }
+
+            if attempt.outcome.is_wrong() && attempt.answer == answer {
+                return Some(format!("{} was already submitted and was {}", answer, attempt.outcome));
+            }
+
+            if let (Some(number), Ok(previous)) = (number, attempt.answer.parse::<i64>()) {
+                if attempt.outcome == Outcome::TooHigh && number >= previous {
+                    return Some(format!("{} is not below {}, which was too high", answer, previous));
+                } else if attempt.outcome == Outcome::TooLow && number <= previous {
+                    return Some(format!("{} is not above {}, which was too low", answer, previous));
+                }
+            }
+        }
+
+        return None;
+    }
+
+    pub fn record(&mut self, attempt: Attempt) -> Result<(), AocError> {
+        let line = format!("{}\t{}\t{}\t{}\t{}\t{}\n",
+            attempt.timestamp, attempt.year, attempt.day, attempt.part, attempt.outcome, attempt.answer);
+
+        let file = OpenOptions::new().create(true).append(true).open(&self.file_name);
+        let result = match file {
+            Ok(mut value) => value.write_all(line.as_bytes()),
+            Err(reason) => Err(reason),
+        };
+
+        if let Err(reason) = result {
+            return Err(AocError::io(&self.file_name, reason));
+        }
+
+        self.attempts.push(attempt);
+        return Ok(());
+    }
+}
+
+pub fn submit(fetcher: &Fetcher, history: &mut History, year: i64, day: i64, part: i64, answer: &str) -> Result<Outcome, AocError> {
+    let response = fetcher.submit_answer(year, day, part, answer)?;
+    let outcome = parse_response(&response);
+
+    history.record(Attempt {
+        timestamp: match SystemTime::now().duration_since(UNIX_EPOCH) {
+            Ok(value) => value.as_secs(),
+            Err(_) => 0,
+        },
+        year: year,
+        day: day,
+        part: part,
+        outcome: outcome.clone(),
+        answer: answer.to_string(),
+    })?;
+
+    return Ok(outcome);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock;
+
+    use std::env;
+    use std::fs;
+    use std::process;
+    use std::time::Duration;
+
+    fn history_file(name: &str) -> String {
+        let file_name = env::temp_dir().join(format!("aoc-submit-{}-{}.tsv", process::id(), name));
+        let _ = fs::remove_file(&file_name);
+        return file_name.to_string_lossy().to_string();
+    }
+
+    fn attempt(part: i64, outcome: Outcome, answer: &str) -> Attempt {
+        return Attempt {
+            timestamp: 1638316800,
+            year: 2021,
+            day: 1,
+            part: part,
+            outcome: outcome,
+            answer: answer.to_string(),
+        };
+    }
+
+    #[test]
+    fn parse_response_outcomes() {
+        let responses = [
+            ("<article><p>That's the right answer!  You are one gold star closer", Outcome::Correct),
+            ("<article><p>That's not the right answer.  If you're stuck", Outcome::Incorrect),
+            ("<article><p>That's not the right answer; your answer is too high.", Outcome::TooHigh),
+            ("<article><p>That's not the right answer; your answer is too low.", Outcome::TooLow),
+            ("<article><p>You gave an answer too recently; you have to wait", Outcome::RateLimited),
+            ("<article><p>You don't seem to be solving the right level.", Outcome::AlreadySolved),
+            ("<html>Something else entirely</html>", Outcome::Unknown),
+        ];
+
+        for (response, expected) in responses {
+            assert_eq!(parse_response(response), expected, "{}", response);
+        }
+    }
+
+    #[test]
+    fn history_round_trip() {
+        let file_name = history_file("round-trip");
+
+        let mut history = History::load(&file_name).unwrap();
+        history.record(attempt(1, Outcome::TooLow, "1500")).unwrap();
+        history.record(attempt(1, Outcome::Correct, "1752")).unwrap();
+
+        let history = History::load(&file_name).unwrap();
+        assert_eq!(history.attempts.len(), 2);
+        assert_eq!(history.attempts[0].outcome, Outcome::TooLow);
+        assert_eq!(history.attempts[1].answer, "1752");
+    }
+
+    #[test]
+    fn history_without_year() {
+        let file_name = history_file("without-year");
+        fs::write(&file_name, "1638316800\t1\t1\ttoo-low\t1500\n1638403200\t2022\t1\t1\tcorrect\t1752\n").unwrap();
+
+        let history = History::load(&file_name).unwrap();
+        assert_eq!(history.attempts[0].year, registry::DEFAULT_YEAR);
+        assert_eq!(history.attempts[0].answer, "1500");
+        assert_eq!(history.attempts[1].year, 2022);
+        assert_eq!(history.attempts[1].outcome, Outcome::Correct);
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let mut history = History::load(&history_file("refuses")).unwrap();
+        history.record(attempt(1, Outcome::Incorrect, "1700")).unwrap();
+        history.record(attempt(1, Outcome::TooHigh, "1800")).unwrap();
+        history.record(attempt(1, Outcome::TooLow, "1600")).unwrap();
+        history.record(attempt(2, Outcome::Correct, "1781")).unwrap();
+
+        assert!(history.refusal(2021, 1, 1, "1700").is_some());
+        assert!(history.refusal(2021, 1, 1, "1900").is_some());
+        assert!(history.refusal(2021, 1, 1, "1600").is_some());
+        assert!(history.refusal(2021, 1, 1, "1752").is_none());
+        assert!(history.refusal(2021, 1, 2, "1782").is_some());
+        assert!(history.refusal(2021, 2, 1, "1700").is_none());
+        assert!(history.refusal(2022, 1, 1, "1700").is_none());
+    }
+
+    #[test]
+    fn submit_posts_answer_and_records_outcome() {
+        let (url, server) = mock::serve(vec![(200, "<article><p>That's the right answer!</p></article>")]);
+
+        let fetcher = Fetcher {
+            base_url: url,
+            session: String::from("secret"),
+            min_interval: Duration::ZERO,
+            timestamp_file: history_file("timestamp"),
+        };
+
+        let mut history = History::load(&history_file("submit")).unwrap();
+        let outcome = submit(&fetcher, &mut history, 2021, 1, 2, "1781").unwrap();
+
+        assert_eq!(outcome, Outcome::Correct);
+        assert_eq!(history.attempts.len(), 1);
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST /2021/day/1/answer HTTP/1.1\r\n"));
+        assert!(requests[0].contains("Cookie: session=secret\r\n"));
+        assert!(requests[0].ends_with("level=2&answer=1781"));
+    }
+}
@@ -0,0 +1,106 @@
+use crate::scaffold::Layout;
+
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+use std::time::SystemTime;
+
+// Polling is plenty fast for a person saving a file,
+// and works anywhere without inotify or similar.
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+// The files a day is built from: its binary, its solution, and
+// every input named after it (`inputs/2021/day_07*.txt`).
+pub fn watched_files(year: i64, day: i64) -> Vec<String> {
+    let layout = Layout::for_year(year);
+    let name = format!("day_{:0>2}", day);
+
+    let mut files = vec![
+        layout.bin(day),
+        layout.source(day),
+    ];
+
+    let mut inputs = vec![];
+    if let Ok(entries) = fs::read_dir(&layout.inputs) {
+        for entry in entries.flatten() {
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            if file_name.starts_with(&name) && file_name.ends_with(".txt") {
+                inputs.push(format!("{}/{}", layout.inputs, file_name));
+            }
+        }
+    }
+
+    inputs.sort();
+    files.extend(inputs);
+    return files;
+}
+
+// A missing file is recorded as `None`, so that
+// deleting or creating one counts as a change too.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Snapshot {
+    files: Vec<(String, Option<SystemTime>)>,
+}
+
+impl Snapshot {
+    pub fn take(files: &[String]) -> Snapshot {
+        let mut snapshot = Snapshot {
+            files: vec![],
+        };
+
+        for file in files {
+            let modified = fs::metadata(Path::new(file)).and_then(|metadata| metadata.modified()).ok();
+            snapshot.files.push((file.to_string(), modified));
+        }
+
+        return snapshot;
+    }
+
+    pub fn changes(&self, newer: &Snapshot) -> Vec<String> {
+        let mut changes = vec![];
+        for (file, modified) in &newer.files {
+            let before = self.files.iter().find(|(name, _)| name == file).map(|(_, value)| value);
+            if before != Some(modified) {
+                changes.push(file.to_string());
+            }
+        }
+
+        for (file, _) in &self.files {
+            if !newer.files.iter().any(|(name, _)| name == file) {
+                changes.push(file.to_string());
+            }
+        }
+
+        return changes;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::env;
+    use std::process;
+
+    #[test]
+    fn snapshot_changes() {
+        let directory = env::temp_dir().join(format!("aoc-watch-{}", process::id()));
+        fs::create_dir_all(&directory).unwrap();
+
+        let file = directory.join("day_07.txt").to_string_lossy().to_string();
+        let missing = directory.join("day_07_example.txt").to_string_lossy().to_string();
+        fs::write(&file, "16,1,2").unwrap();
+
+        let files = vec![file.clone(), missing.clone()];
+        let before = Snapshot::take(&files);
+        assert!(before.changes(&Snapshot::take(&files)).is_empty());
+
+        // Modification times can be coarse, so
+        // move the clock forward by hand instead.
+        let later = SystemTime::now() + Duration::from_secs(10);
+        fs::File::options().write(true).open(&file).unwrap().set_modified(later).unwrap();
+        fs::write(&missing, "").unwrap();
+
+        assert_eq!(before.changes(&Snapshot::take(&files)), [file, missing]);
+    }
+}